@@ -0,0 +1,99 @@
+//! Compares the bounding sphere heuristics across a set of test meshes, reporting the radius each
+//! produces and how long it takes to fit. Run with `cargo bench --bench sphere_heuristics`.
+
+use bevy::{prelude::*, render::mesh::VertexAttributeValues};
+use bevy_mod_bounding::{sphere::BSphere, BoundingVolume};
+use std::time::{Duration, Instant};
+
+const ITERATIONS: u32 = 100;
+
+/// A small linear congruential generator, so the point clouds are the same on every run.
+struct Lcg(u64);
+
+impl Lcg {
+    /// A pseudo-random float in `-1.0..1.0`.
+    fn next_f32(&mut self) -> f32 {
+        self.0 = self
+            .0
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        ((self.0 >> 40) as f32 / (1u64 << 24) as f32) * 2.0 - 1.0
+    }
+
+    fn next_vec3(&mut self) -> Vec3 {
+        Vec3::new(self.next_f32(), self.next_f32(), self.next_f32())
+    }
+}
+
+fn positions(mesh: Mesh) -> Vec<Vec3> {
+    match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+        Some(VertexAttributeValues::Float32x3(positions)) => {
+            positions.iter().map(|&position| position.into()).collect()
+        }
+        _ => unreachable!("bevy's shapes always have float positions"),
+    }
+}
+
+fn test_meshes() -> Vec<(&'static str, Vec<Vec3>)> {
+    let mut rng = Lcg(42);
+    // A dense cluster of points with a few distant outliers on one side, where the poles that
+    // Ritter's fit seeds from are skewed toward the outliers.
+    let mut clustered: Vec<Vec3> = (0..5000).map(|_| rng.next_vec3()).collect();
+    clustered.extend((0..5).map(|_| Vec3::new(8.0, 0.0, 0.0) + rng.next_vec3() * 0.5));
+    // An elongated, evenly sampled rod, where the poles that Ritter's fit seeds from lie on the
+    // final sphere.
+    let rod = (0..5000)
+        .map(|_| rng.next_vec3() * Vec3::new(10.0, 0.5, 0.5))
+        .collect();
+    vec![
+        (
+            "icosphere",
+            positions(Mesh::from(shape::Icosphere {
+                radius: 1.0,
+                subdivisions: 5,
+            })),
+        ),
+        ("cube", positions(Mesh::from(shape::Cube { size: 2.0 }))),
+        ("box", positions(Mesh::from(shape::Box::new(4.0, 1.0, 0.5)))),
+        ("torus", positions(Mesh::from(shape::Torus::default()))),
+        ("clustered", clustered),
+        ("rod", rod),
+    ]
+}
+
+/// Fits the sphere `ITERATIONS` times, returning it with the mean time per fit.
+fn measure(fit: impl Fn() -> BSphere) -> (BSphere, Duration) {
+    let start = Instant::now();
+    let mut sphere = fit();
+    for _ in 1..ITERATIONS {
+        sphere = fit();
+    }
+    (sphere, start.elapsed() / ITERATIONS)
+}
+
+fn main() {
+    let transform = GlobalTransform::identity();
+    println!(
+        "{:<10} {:>8} {:>14} {:>14} {:>14}",
+        "mesh", "vertices", "ritter", "centroid", "origin"
+    );
+    for (name, vertices) in test_meshes() {
+        let heuristics = [
+            measure(|| BSphere::from_vertices(&vertices, &transform)),
+            measure(|| BSphere::from_vertices_centroid(&vertices)),
+            measure(|| BSphere::from_vertices_origin_centered(&vertices)),
+        ];
+        let columns: Vec<String> = heuristics
+            .iter()
+            .map(|(sphere, time)| format!("{:.3} {:>6.0?}", sphere.mesh_space_radius(), time))
+            .collect();
+        println!(
+            "{:<10} {:>8} {:>14} {:>14} {:>14}",
+            name,
+            vertices.len(),
+            columns[0],
+            columns[1],
+            columns[2]
+        );
+    }
+}
@@ -36,8 +36,7 @@ fn setup(
             transform: Transform::from_translation(Vec3::new(-1.0, 0.0, 0.0)),
             ..Default::default()
         })
-        .insert(Bounded::<aabb::Aabb>::default())
-        .insert(debug::DebugBounds)
+        .insert_bundle(debug::BoundedBundle::<aabb::Aabb>::default())
         .insert(Rotator);
     // OBB
     commands
@@ -47,8 +46,7 @@ fn setup(
             transform: Transform::from_translation(Vec3::new(0.0, 0.0, 0.0)),
             ..Default::default()
         })
-        .insert(Bounded::<obb::Obb>::default())
-        .insert(debug::DebugBounds)
+        .insert_bundle(debug::BoundedBundle::<obb::Obb>::default())
         .insert(Rotator);
     // Sphere
     commands
@@ -58,8 +56,7 @@ fn setup(
             transform: Transform::from_translation(Vec3::new(1.0, 0.0, 0.0)),
             ..Default::default()
         })
-        .insert(Bounded::<sphere::BSphere>::default())
-        .insert(debug::DebugBounds)
+        .insert_bundle(debug::BoundedBundle::<sphere::BSphere>::default())
         .insert(Rotator);
     // Light
     commands.spawn_bundle(PointLightBundle {
@@ -1,9 +1,12 @@
-use crate::BoundingVolume;
-use bevy::{
-    prelude::*,
-    render::{mesh::VertexAttributeValues, pipeline::PrimitiveTopology},
+use crate::{
+    bytes::{floats_from_bytes, floats_to_bytes},
+    intersect::{self, segment_entry_extents, separating_axis, sphere_intersects_cone},
+    mesh::{box_triangle_mesh, line_list_mesh, transform_positions},
+    sphere::BSphere,
+    vertex::{finite_vertices, VertexSource},
+    BoundingError, BoundingKind, BoundingVolume, DebugMeshOptions, DebugMeshTopology,
 };
-use core::panic;
+use bevy::prelude::*;
 
 /// Defines an axis-aligned bounding box in mesh space - that is - the bounding box is located at
 /// the mesh's origin, but the current [GlobalTransform] has been used to rotate and scale the mesh
@@ -32,6 +35,15 @@ impl Aabb {
     pub fn maximums(&self) -> Vec3 {
         self.maximums
     }
+    /// Returns the ends of the diagonal of the box, from its minimums to its maximums, moved back
+    /// to the mesh space of the mesh it was fit to with `transform`.
+    pub fn mesh_space_diagonal(&self, transform: &GlobalTransform) -> (Vec3, Vec3) {
+        let world_to_mesh = Aabb::inverse_rotation_scale(transform);
+        (
+            world_to_mesh.transform_point3(self.minimums),
+            world_to_mesh.transform_point3(self.maximums),
+        )
+    }
     /// Returns the vertices of the bounding box in world space, given the current mesh transform.
     pub fn vertices(&self, transform: GlobalTransform) -> [Vec3; 8] {
         let vertices_mesh_space = self.vertices_mesh_space();
@@ -67,72 +79,573 @@ impl Aabb {
             Vec3::new(self.maximums.x, self.minimums.y, self.minimums.z),
         ]
     }
+    /// Returns the corners of the bounding box in world space, given the current mesh transform.
+    pub fn corners(&self, transform: &GlobalTransform) -> BoxCorners {
+        BoxCorners::new(self.vertices(*transform))
+    }
+    /// Returns the corners of the bounding box in mesh space.
+    pub fn corners_mesh_space(&self) -> BoxCorners {
+        BoxCorners::new(self.vertices_mesh_space())
+    }
+    /// Returns the 12 edges of the bounding box in world space, given the current mesh transform,
+    /// as pairs of edge endpoints.
+    pub fn edges(&self, transform: &GlobalTransform) -> [(Vec3, Vec3); 12] {
+        box_edges(&self.vertices(*transform))
+    }
+    /// Returns the 12 edges of the bounding box in mesh space, as pairs of edge endpoints.
+    pub fn edges_mesh_space(&self) -> [(Vec3, Vec3); 12] {
+        box_edges(&self.vertices_mesh_space())
+    }
     pub fn from_extents(minimums: Vec3, maximums: Vec3) -> Self {
         Aabb { minimums, maximums }
     }
+    /// Returns the box as a flat binary blob, for precomputed bounds that are loaded without
+    /// parsing. The layout is the minimums then the maximums, each as x, y, z, for 6 little-endian
+    /// `f32`s in total.
+    pub fn to_bytes(&self) -> [u8; 24] {
+        floats_to_bytes([
+            self.minimums.x,
+            self.minimums.y,
+            self.minimums.z,
+            self.maximums.x,
+            self.maximums.y,
+            self.maximums.z,
+        ])
+    }
+    /// Reads a box written by [Aabb::to_bytes]. Fails if `bytes` isn't 24 bytes long.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Aabb, BoundingError> {
+        let [min_x, min_y, min_z, max_x, max_y, max_z]: [f32; 6] = floats_from_bytes(bytes)?;
+        Ok(Aabb::from_extents(
+            Vec3::new(min_x, min_y, min_z),
+            Vec3::new(max_x, max_y, max_z),
+        ))
+    }
     /// Given a set of points, fit an axis oriented bounding box to the vertices by finding the
-    /// extents of the mesh.
+    /// extents of the mesh. Non-finite points are skipped; if there are no finite points, an empty
+    /// box at the origin is returned.
     pub fn compute_aabb(vertices: &[Vec3]) -> Aabb {
+        let vertices = finite_vertices(vertices);
+        if vertices.is_empty() {
+            return Aabb::from_extents(Vec3::ZERO, Vec3::ZERO);
+        }
         let mut maximums = Vec3::new(f32::MIN, f32::MIN, f32::MIN);
         let mut minimums = Vec3::new(f32::MAX, f32::MAX, f32::MAX);
         for vertex in vertices.iter() {
             maximums = vertex.max(maximums);
             minimums = vertex.min(minimums);
         }
-        Aabb { minimums, maximums }
+        Aabb::from_extents(minimums, maximums)
+    }
+    /// The debug mesh is a child of the bounded entity, so the rotation and scale of the parent
+    /// will be applied to it. This returns the transform that negates them, so the box stays axis
+    /// aligned.
+    fn inverse_rotation_scale(transform: &GlobalTransform) -> Mat4 {
+        Aabb::linear_part(transform).inverse()
+    }
+    /// Returns the matrix of the [GlobalTransform] without its translation, which is its rotation
+    /// followed by its scale. A [GlobalTransform] only stores a translation, rotation, and scale,
+    /// so it can't represent shear: the shear of a rotated child under a non-uniformly scaled
+    /// parent is already lost when transforms are propagated, and can't be recovered here.
+    fn linear_part(transform: &GlobalTransform) -> Mat4 {
+        let mut matrix = transform.compute_matrix();
+        matrix.w_axis = Vec4::W;
+        matrix
+    }
+    /// Returns the volume of the bounding box. The extents are already rotated and scaled, so this
+    /// is the volume in world space.
+    pub fn volume(&self) -> f32 {
+        let size = self.maximums - self.minimums;
+        size.x * size.y * size.z
+    }
+    /// Returns the smallest [Aabb] that contains both this box and `other`, which must be in the
+    /// same space.
+    pub fn merged(&self, other: &Aabb) -> Aabb {
+        Aabb::from_extents(
+            self.minimums.min(other.minimums),
+            self.maximums.max(other.maximums),
+        )
+    }
+    /// Grows the bounding box so it contains the world space `point`, given the current mesh
+    /// transform. The extents of the box are already rotated and scaled, so only the translation
+    /// of the transform is undone to bring the point into the space of the extents.
+    pub fn encompass_point(&mut self, transform: &GlobalTransform, point: Vec3) {
+        let point = point - transform.translation;
+        self.minimums = self.minimums.min(point);
+        self.maximums = self.maximums.max(point);
+    }
+    /// Returns the contact normal and penetration depth of a collision between this box and a
+    /// bounding sphere, or `None` if they don't overlap. The normal points from the box toward
+    /// the sphere, so moving the sphere along it by the depth resolves the collision. If the
+    /// center of the sphere is inside of the box, it is pushed out through the nearest face.
+    pub fn sphere_collision(
+        &self,
+        box_transform: &GlobalTransform,
+        sphere: &BSphere,
+        sphere_transform: &GlobalTransform,
+    ) -> Option<(Vec3, f32)> {
+        let aabb = self.world_aabb(box_transform);
+        let center = sphere.origin(*sphere_transform);
+        let radius = sphere.radius(sphere_transform);
+        let closest = center.max(aabb.minimums).min(aabb.maximums);
+        let offset = center - closest;
+        let distance = offset.length();
+        if distance > 0.0 {
+            if distance < radius {
+                Some((offset / distance, radius - distance))
+            } else {
+                None
+            }
+        } else {
+            // The center is inside of the box, so find the face it is closest to.
+            let to_minimums = center - aabb.minimums;
+            let to_maximums = aabb.maximums - center;
+            let mut normal = Vec3::ZERO;
+            let mut face_distance = f32::MAX;
+            for (axis, direction) in [Vec3::X, Vec3::Y, Vec3::Z].iter().enumerate() {
+                if to_minimums[axis] < face_distance {
+                    face_distance = to_minimums[axis];
+                    normal = -*direction;
+                }
+                if to_maximums[axis] < face_distance {
+                    face_distance = to_maximums[axis];
+                    normal = *direction;
+                }
+            }
+            Some((normal, face_distance + radius))
+        }
+    }
+    /// Returns true iff the bounding box may intersect the cone with its tip at `apex`, opening
+    /// along `dir` with the given `half_angle` in radians, and capped at `range` along `dir`. This
+    /// is conservative: the sphere around the world space AABB of the box is tested, see
+    /// [BSphere::intersects_cone](crate::sphere::BSphere::intersects_cone).
+    pub fn intersects_cone(
+        &self,
+        transform: &GlobalTransform,
+        apex: Vec3,
+        dir: Vec3,
+        half_angle: f32,
+        range: f32,
+    ) -> bool {
+        let aabb = self.world_aabb(transform);
+        sphere_intersects_cone(
+            (aabb.minimums() + aabb.maximums()) * 0.5,
+            (aabb.maximums() - aabb.minimums()).length() * 0.5,
+            apex,
+            dir,
+            half_angle,
+            range,
+        )
+    }
+    /// Returns true iff the line segment from `a` to `b`, in world space, intersects the bounding
+    /// box. This includes the case where the segment lies entirely inside the box.
+    pub fn segment_intersects(&self, transform: &GlobalTransform, a: Vec3, b: Vec3) -> bool {
+        intersect::segment_aabb(self, transform, a, b)
+    }
+    /// Sweeps a sphere of `sphere_radius` from `from` to `to`, in world space, against the
+    /// bounding box, and returns the fraction of the sweep, from 0 to 1, at which the sphere first
+    /// touches the box, or `None` if it never does. A sphere that already overlaps the box at
+    /// `from` returns 0. This is a continuous collision test that keeps fast projectiles from
+    /// tunneling through thin objects.
+    ///
+    /// The path of the center is tested against the box inflated by the radius, so this is
+    /// conservative near the edges and corners of the box, where the inflated box is square
+    /// rather than rounded.
+    pub fn sphere_sweep(
+        &self,
+        box_transform: &GlobalTransform,
+        sphere_radius: f32,
+        from: Vec3,
+        to: Vec3,
+    ) -> Option<f32> {
+        let aabb = self.world_aabb(box_transform);
+        let closest = from.max(aabb.minimums).min(aabb.maximums);
+        if closest.distance_squared(from) <= sphere_radius * sphere_radius {
+            return Some(0.0);
+        }
+        let radius = Vec3::splat(sphere_radius);
+        segment_entry_extents(aabb.minimums - radius, aabb.maximums + radius, from, to)
+    }
+    /// Returns the point on the surface of the bounding box closest to the world space `point`,
+    /// and the [BoxFeature] that it lies on. Points outside of the box are classified by how many
+    /// axes had to be clamped to reach the box: one gives a face, two an edge, and three a vertex.
+    /// Points inside of the box are projected onto the nearest face.
+    pub fn closest_feature(&self, transform: &GlobalTransform, point: Vec3) -> (Vec3, BoxFeature) {
+        let minimums = transform.translation + self.minimums;
+        let maximums = transform.translation + self.maximums;
+        let closest = point.max(minimums).min(maximums);
+        // The side of the box that each axis was clamped to, if any.
+        let mut clamped = [None; 3];
+        for (axis, side) in clamped.iter_mut().enumerate() {
+            if point[axis] < minimums[axis] {
+                *side = Some(false);
+            } else if point[axis] > maximums[axis] {
+                *side = Some(true);
+            }
+        }
+        let feature = match clamped {
+            [None, None, None] => {
+                // The point is inside of the box, so it is moved onto the nearest face.
+                let mut face = (0, false);
+                let mut face_distance = f32::MAX;
+                for axis in 0..3 {
+                    if point[axis] - minimums[axis] < face_distance {
+                        face_distance = point[axis] - minimums[axis];
+                        face = (axis, false);
+                    }
+                    if maximums[axis] - point[axis] < face_distance {
+                        face_distance = maximums[axis] - point[axis];
+                        face = (axis, true);
+                    }
+                }
+                let (axis, positive) = face;
+                let mut on_face = point;
+                on_face[axis] = if positive {
+                    maximums[axis]
+                } else {
+                    minimums[axis]
+                };
+                return (on_face, BoxFeature::Face { axis, positive });
+            }
+            [Some(x), Some(y), Some(z)] => BoxFeature::Vertex(box_vertex_index([x, y, z])),
+            _ => {
+                let clamped_count = clamped.iter().filter(|side| side.is_some()).count();
+                let free_axis = clamped.iter().position(|side| side.is_none()).unwrap();
+                if clamped_count == 1 {
+                    let axis = clamped.iter().position(|side| side.is_some()).unwrap();
+                    BoxFeature::Face {
+                        axis,
+                        positive: clamped[axis].unwrap(),
+                    }
+                } else {
+                    // The edge runs along the free axis, between the vertices on either side.
+                    let mut sides = [
+                        clamped[0].unwrap_or(false),
+                        clamped[1].unwrap_or(false),
+                        clamped[2].unwrap_or(false),
+                    ];
+                    let start = box_vertex_index(sides);
+                    sides[free_axis] = true;
+                    let end = box_vertex_index(sides);
+                    BoxFeature::Edge(
+                        BOX_EDGES
+                            .iter()
+                            .position(|&edge| edge == (start, end) || edge == (end, start))
+                            .unwrap(),
+                    )
+                }
+            }
+        };
+        (closest, feature)
+    }
+    /// Returns true iff the world space triangle `v0`, `v1`, `v2` intersects the bounding box,
+    /// using the separating axis test over the 13 candidate axes of a triangle-box pair.
+    /// Degenerate (zero-area) triangles are tested as the segment or point they collapse to.
+    pub fn intersects_triangle(
+        &self,
+        transform: &GlobalTransform,
+        v0: Vec3,
+        v1: Vec3,
+        v2: Vec3,
+    ) -> bool {
+        let minimums = transform.translation + self.minimums;
+        let maximums = transform.translation + self.maximums;
+        let center = (minimums + maximums) * 0.5;
+        let half_extents = (maximums - minimums) * 0.5;
+        // Work relative to the box center, so the box projects symmetrically onto every axis.
+        let triangle = [v0 - center, v1 - center, v2 - center];
+        let edges = [
+            triangle[1] - triangle[0],
+            triangle[2] - triangle[1],
+            triangle[0] - triangle[2],
+        ];
+        let box_axes = [Vec3::X, Vec3::Y, Vec3::Z];
+        let separated_on = |axis: Option<Vec3>| {
+            // Axes from parallel edges, or the normal of a degenerate triangle, can't separate
+            // anything.
+            let axis = match axis {
+                Some(axis) => axis,
+                None => return false,
+            };
+            let projections = triangle.iter().map(|vertex| vertex.dot(axis));
+            let min = projections.clone().fold(f32::MAX, f32::min);
+            let max = projections.fold(f32::MIN, f32::max);
+            let radius = half_extents.dot(axis.abs());
+            min > radius || max < -radius
+        };
+        for edge in edges.iter() {
+            for box_axis in box_axes.iter() {
+                if separated_on(separating_axis(*edge, *box_axis)) {
+                    return false;
+                }
+            }
+        }
+        if box_axes
+            .iter()
+            .any(|&box_axis| separated_on(Some(box_axis)))
+        {
+            return false;
+        }
+        !separated_on(separating_axis(edges[0], edges[1]))
     }
 }
 
-impl BoundingVolume for Aabb {
-    fn new(mesh: &Mesh, transform: &GlobalTransform) -> Self {
-        let transform_matrix = Transform {
-            translation: Vec3::ZERO,
-            rotation: transform.rotation,
-            scale: transform.scale,
+/// Incrementally builds an [Aabb] from mesh space points, for meshes that are streamed in chunks,
+/// or to use the fit outside of the ECS. The result is identical to fitting all points at once.
+#[derive(Debug, Clone)]
+pub struct AabbBuilder {
+    minimums: Vec3,
+    maximums: Vec3,
+}
+impl Default for AabbBuilder {
+    fn default() -> Self {
+        AabbBuilder {
+            minimums: Vec3::new(f32::MAX, f32::MAX, f32::MAX),
+            maximums: Vec3::new(f32::MIN, f32::MIN, f32::MIN),
         }
-        .compute_matrix();
-        // Grab a vector of vertex coordinates we can use to iterate through
-        if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
-            panic!("Non-TriangleList mesh supplied for bounding box generation")
+    }
+}
+impl AabbBuilder {
+    /// Grows the box to contain the `points`. Non-finite points are skipped.
+    pub fn push_points(&mut self, points: &[Vec3]) {
+        for point in finite_vertices(points).iter() {
+            self.minimums = point.min(self.minimums);
+            self.maximums = point.max(self.maximums);
         }
-        let vertices: Vec<Vec3> = match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
-            None => panic!("Mesh does not contain vertex positions"),
-            Some(vertex_values) => match &vertex_values {
-                VertexAttributeValues::Float32x3(positions) => positions
-                    .iter()
-                    .map(|coordinates| transform_matrix.transform_point3(Vec3::from(*coordinates)))
-                    .collect(),
-                _ => panic!("Unexpected vertex types in ATTRIBUTE_POSITION"),
-            },
-        };
+    }
+    /// Returns the box containing all pushed points, or an empty box at the origin if no points
+    /// were pushed.
+    pub fn finish(self) -> Aabb {
+        if self.minimums.cmpgt(self.maximums).any() {
+            Aabb::from_extents(Vec3::ZERO, Vec3::ZERO)
+        } else {
+            Aabb::from_extents(self.minimums, self.maximums)
+        }
+    }
+}
+
+/// The 8 corners of a box, as returned by [Aabb::corners] or [crate::obb::Obb::corners],
+/// with named accessors so the order of the vertices doesn't need to be memorized. "Top" and
+/// "bottom" refer to the +Y and -Y faces of the box in its own frame, "front" and "back" to +Z and
+/// -Z, and "right" and "left" to +X and -X.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoxCorners([Vec3; 8]);
+
+impl BoxCorners {
+    /// Wraps the vertices of a box, in the order of [Aabb::vertices_mesh_space].
+    pub fn new(vertices: [Vec3; 8]) -> Self {
+        BoxCorners(vertices)
+    }
+    /// Returns the vertices, in the order of [Aabb::vertices_mesh_space].
+    pub fn vertices(&self) -> &[Vec3; 8] {
+        &self.0
+    }
+    /// The corner at the maximum of all three axes.
+    pub fn max_corner(&self) -> Vec3 {
+        self.0[0]
+    }
+    /// The corner at the minimum of all three axes.
+    pub fn min_corner(&self) -> Vec3 {
+        self.0[6]
+    }
+    /// The corners of the +Y face, wound around the face.
+    pub fn top_face(&self) -> [Vec3; 4] {
+        self.face([0, 1, 2, 3])
+    }
+    /// The corners of the -Y face, wound around the face.
+    pub fn bottom_face(&self) -> [Vec3; 4] {
+        self.face([4, 5, 6, 7])
+    }
+    /// The corners of the +Z face, wound around the face.
+    pub fn front_face(&self) -> [Vec3; 4] {
+        self.face([0, 1, 5, 4])
+    }
+    /// The corners of the -Z face, wound around the face.
+    pub fn back_face(&self) -> [Vec3; 4] {
+        self.face([3, 2, 6, 7])
+    }
+    /// The corners of the +X face, wound around the face.
+    pub fn right_face(&self) -> [Vec3; 4] {
+        self.face([0, 3, 7, 4])
+    }
+    /// The corners of the -X face, wound around the face.
+    pub fn left_face(&self) -> [Vec3; 4] {
+        self.face([1, 2, 6, 5])
+    }
+    /// Returns the 12 edges of the box, as pairs of edge endpoints.
+    pub fn edges(&self) -> [(Vec3, Vec3); 12] {
+        box_edges(&self.0)
+    }
+    fn face(&self, indices: [usize; 4]) -> [Vec3; 4] {
+        [
+            self.0[indices[0]],
+            self.0[indices[1]],
+            self.0[indices[2]],
+            self.0[indices[3]],
+        ]
+    }
+}
+
+impl From<BoxCorners> for [Vec3; 8] {
+    fn from(corners: BoxCorners) -> Self {
+        corners.0
+    }
+}
+
+/// A feature of the surface of a box, as returned by [Aabb::closest_feature].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoxFeature {
+    /// The face perpendicular to the world `axis` (0 for x, 1 for y, 2 for z), on the positive or
+    /// negative side of the box.
+    Face { axis: usize, positive: bool },
+    /// The edge with this index into [Aabb::edges].
+    Edge(usize),
+    /// The vertex with this index into [Aabb::vertices].
+    Vertex(usize),
+}
+
+/// Whether each of the vertices of a box, in the order of [Aabb::vertices_mesh_space], lies on the
+/// maximum side of the box along the x, y, and z axes.
+#[rustfmt::skip]
+const BOX_VERTEX_SIDES: [[bool; 3]; 8] = [
+    [true, true, true], [false, true, true], [false, true, false], [true, true, false],
+    [true, false, true], [false, false, true], [false, false, false], [true, false, false],
+];
+
+/// Returns the index of the box vertex on the maximum (`true`) or minimum side of each axis.
+fn box_vertex_index(sides: [bool; 3]) -> usize {
+    BOX_VERTEX_SIDES
+        .iter()
+        .position(|vertex_sides| *vertex_sides == sides)
+        .unwrap()
+}
+
+/// Pairs of indices into the vertices of a box, as returned by [Aabb::vertices_mesh_space], that
+/// form the edges of the box.
+#[rustfmt::skip]
+const BOX_EDGES: [(usize, usize); 12] = [
+    (0, 1), (1, 2), (2, 3), (3, 0), // Top ring
+    (4, 5), (5, 6), (6, 7), (7, 4), // Bottom ring
+    (0, 4), (1, 5), (2, 6), (3, 7), // Verticals
+];
+
+/// Returns the edges of a box, given its 8 vertices in the order of [Aabb::vertices_mesh_space].
+pub(crate) fn box_edges(vertices: &[Vec3; 8]) -> [(Vec3, Vec3); 12] {
+    let mut edges = [(Vec3::ZERO, Vec3::ZERO); 12];
+    for (edge, (start, end)) in edges.iter_mut().zip(BOX_EDGES.iter()) {
+        *edge = (vertices[*start], vertices[*end]);
+    }
+    edges
+}
+
+impl BoundingVolume for Aabb {
+    fn from_vertices(vertices: &[Vec3], transform: &GlobalTransform) -> Self {
+        let transform_matrix = Aabb::linear_part(transform);
+        let vertices: Vec<Vec3> = vertices
+            .iter()
+            .map(|vertex| transform_matrix.transform_point3(*vertex))
+            .collect();
         Self::compute_aabb(&vertices)
     }
 
-    fn new_debug_mesh(&self, transform: &GlobalTransform) -> Mesh {
-        let mut mesh = Mesh::from(self);
-        let inverse_transform = Transform::from_matrix(
-            Mat4::from_scale_rotation_translation(transform.scale, transform.rotation, Vec3::ZERO)
-                .inverse(),
-        );
-        match mesh.attribute_mut(Mesh::ATTRIBUTE_POSITION) {
-            None => panic!("Mesh does not contain vertex positions"),
-            Some(vertex_values) => match vertex_values {
-                VertexAttributeValues::Float32x3(ref mut positions) => {
-                    *positions = positions
-                        .iter()
-                        .map(|coordinates| {
-                            inverse_transform.mul_vec3(Vec3::from(*coordinates)).into()
-                        })
-                        .collect()
-                }
-                _ => panic!("Unexpected vertex types in ATTRIBUTE_POSITION"),
-            },
+    fn new_debug_mesh(
+        &self,
+        transform: &GlobalTransform,
+        options: &DebugMeshOptions,
+    ) -> Result<Mesh, BoundingError> {
+        let aabb = self.inflated(options.inflation);
+        let mut mesh = match options.topology {
+            DebugMeshTopology::Lines => Mesh::from(&aabb),
+            DebugMeshTopology::Triangles => box_triangle_mesh(&aabb.vertices_mesh_space()),
         };
-        mesh
+        transform_positions(&mut mesh, Aabb::inverse_rotation_scale(transform))?;
+        Ok(mesh)
+    }
+
+    /// The rotation and scale determine the mesh space extents, so the box is refit from the
+    /// vertices. The systems only call this once the rotation or scale differ from the
+    /// [BoundsPose](crate::BoundsPose) of the box, so pure translations never re-read the mesh.
+    fn update_on_transform_change(
+        &self,
+        vertices: &VertexSource,
+        transform: &GlobalTransform,
+    ) -> Option<Self> {
+        match vertices.vertices() {
+            Ok(vertices) => Some(Self::from_vertices(&vertices, transform)),
+            Err(err) => {
+                error!("Could not update a bounding box: {}", err);
+                None
+            }
+        }
+    }
+
+    fn world_aabb(&self, bound_vol_position: &GlobalTransform) -> Aabb {
+        Aabb::from_extents(
+            bound_vol_position.translation + self.minimums,
+            bound_vol_position.translation + self.maximums,
+        )
+    }
+
+    fn project_onto_axis(&self, bound_vol_position: &GlobalTransform, axis: Vec3) -> (f32, f32) {
+        let center = bound_vol_position.translation + (self.minimums + self.maximums) * 0.5;
+        let half_extents = (self.maximums - self.minimums) * 0.5;
+        let center_projection = center.dot(axis);
+        let radius = half_extents.dot(axis.abs());
+        (center_projection - radius, center_projection + radius)
+    }
+
+    fn intersects(
+        &self,
+        bound_vol_position: &GlobalTransform,
+        other: &Self,
+        other_position: &GlobalTransform,
+    ) -> bool {
+        intersect::aabb_aabb(self, bound_vol_position, other, other_position)
+    }
+
+    fn to_world_space(&self, bound_vol_position: &GlobalTransform) -> Self {
+        self.world_aabb(bound_vol_position)
+    }
+
+    fn center(&self, bound_vol_position: &GlobalTransform) -> Vec3 {
+        bound_vol_position.translation + (self.minimums + self.maximums) * 0.5
+    }
+
+    fn contains_point(&self, bound_vol_position: &GlobalTransform, point: Vec3) -> bool {
+        let aabb = self.world_aabb(bound_vol_position);
+        aabb.minimums.cmple(point).all() && point.cmple(aabb.maximums).all()
     }
 
-    fn update_on_transform_change(&self, mesh: &Mesh, transform: &GlobalTransform) -> Option<Self> {
-        Some(Self::new(mesh, transform))
+    fn world_volume(&self, _bound_vol_position: &GlobalTransform) -> f32 {
+        self.volume()
+    }
+
+    fn longest_axis(&self, _bound_vol_position: &GlobalTransform) -> (Vec3, f32) {
+        let size = self.maximums - self.minimums;
+        [Vec3::X, Vec3::Y, Vec3::Z]
+            .iter()
+            .zip([size.x, size.y, size.z].iter())
+            .fold((Vec3::X, size.x), |longest, (&axis, &length)| {
+                if length > longest.1 {
+                    (axis, length)
+                } else {
+                    longest
+                }
+            })
+    }
+
+    fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.minimums.abs_diff_eq(other.minimums, epsilon)
+            && self.maximums.abs_diff_eq(other.maximums, epsilon)
+    }
+
+    fn inflated(&self, factor: f32) -> Self {
+        let center = (self.minimums + self.maximums) * 0.5;
+        let half_extents = (self.maximums - self.minimums) * 0.5 * factor;
+        Aabb::from_extents(center - half_extents, center + half_extents)
+    }
+
+    fn kind(&self) -> BoundingKind {
+        BoundingKind::Aabb
     }
 
     fn outside_plane(
@@ -150,3 +663,130 @@ impl BoundingVolume for Aabb {
         true
     }
 }
+
+impl From<&Aabb> for Mesh {
+    fn from(aabb: &Aabb) -> Self {
+        line_list_mesh(&aabb.edges_mesh_space())
+    }
+}
+
+/// A loose [Aabb] that only ever grows to enclose each new pose of its mesh, and never shrinks.
+/// For meshes that jitter or animate within a range, such as animated characters, the bounds then
+/// settle on the range of motion instead of changing every frame, which keeps the broad phase from
+/// thrashing, in exchange for a looser box. The box is refit tightly when the mesh itself changes,
+/// or with [LooseAabb::reset].
+#[derive(Debug, Clone, Default, Component)]
+pub struct LooseAabb(Aabb);
+
+impl LooseAabb {
+    /// Returns the [Aabb] enclosing every pose seen since the box was last fit tightly.
+    pub fn aabb(&self) -> &Aabb {
+        &self.0
+    }
+    /// Discards the poses seen so far, and fits the box tightly to the mesh, or any other
+    /// [VertexSource], with its current transform. The box is left unchanged if the mesh can't be
+    /// read.
+    pub fn reset<'a>(
+        &mut self,
+        source: impl Into<VertexSource<'a>>,
+        transform: &GlobalTransform,
+    ) -> Result<(), BoundingError> {
+        *self = LooseAabb::new(source, transform)?;
+        Ok(())
+    }
+}
+
+impl BoundingVolume for LooseAabb {
+    fn from_vertices(vertices: &[Vec3], transform: &GlobalTransform) -> Self {
+        LooseAabb(Aabb::from_vertices(vertices, transform))
+    }
+
+    fn new_debug_mesh(
+        &self,
+        transform: &GlobalTransform,
+        options: &DebugMeshOptions,
+    ) -> Result<Mesh, BoundingError> {
+        self.0.new_debug_mesh(transform, options)
+    }
+
+    /// The box for the new pose is merged into the stored box, which is only written back if it
+    /// grew.
+    fn update_on_transform_change(
+        &self,
+        vertices: &VertexSource,
+        transform: &GlobalTransform,
+    ) -> Option<Self> {
+        let pose = self.0.update_on_transform_change(vertices, transform)?;
+        let merged = self.0.merged(&pose);
+        if merged.approx_eq(&self.0, 0.0) {
+            None
+        } else {
+            Some(LooseAabb(merged))
+        }
+    }
+
+    fn world_aabb(&self, bound_vol_position: &GlobalTransform) -> Aabb {
+        self.0.world_aabb(bound_vol_position)
+    }
+
+    fn project_onto_axis(&self, bound_vol_position: &GlobalTransform, axis: Vec3) -> (f32, f32) {
+        self.0.project_onto_axis(bound_vol_position, axis)
+    }
+
+    fn intersects(
+        &self,
+        bound_vol_position: &GlobalTransform,
+        other: &Self,
+        other_position: &GlobalTransform,
+    ) -> bool {
+        self.0
+            .intersects(bound_vol_position, &other.0, other_position)
+    }
+
+    fn to_world_space(&self, bound_vol_position: &GlobalTransform) -> Self {
+        LooseAabb(self.0.to_world_space(bound_vol_position))
+    }
+
+    fn center(&self, bound_vol_position: &GlobalTransform) -> Vec3 {
+        self.0.center(bound_vol_position)
+    }
+
+    fn contains_point(&self, bound_vol_position: &GlobalTransform, point: Vec3) -> bool {
+        self.0.contains_point(bound_vol_position, point)
+    }
+
+    fn world_volume(&self, bound_vol_position: &GlobalTransform) -> f32 {
+        self.0.world_volume(bound_vol_position)
+    }
+
+    fn longest_axis(&self, bound_vol_position: &GlobalTransform) -> (Vec3, f32) {
+        self.0.longest_axis(bound_vol_position)
+    }
+
+    fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.0.approx_eq(&other.0, epsilon)
+    }
+
+    fn inflated(&self, factor: f32) -> Self {
+        LooseAabb(self.0.inflated(factor))
+    }
+
+    fn kind(&self) -> BoundingKind {
+        BoundingKind::Aabb
+    }
+
+    fn outside_plane(
+        &self,
+        bound_vol_position: &GlobalTransform,
+        point: Vec3,
+        normal: Vec3,
+    ) -> bool {
+        self.0.outside_plane(bound_vol_position, point, normal)
+    }
+}
+
+impl From<&LooseAabb> for Mesh {
+    fn from(loose_aabb: &LooseAabb) -> Self {
+        Mesh::from(&loose_aabb.0)
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{
+    aabb::Aabb,
+    obb::Obb,
+    sphere::BSphere,
+    vertex::{
+        BoundsProxyPoints, BoundsSourceAttribute, BoundsVertexRange, LockBoundsToHandle,
+        PositionDecoder, VertexSource,
+    },
+    BoundingError, BoundingSystem, BoundingVolume, BoundsReferenceTransform,
+};
+use bevy::prelude::*;
+
+/// The tightest bounding volume found by [best_fit].
+#[derive(Debug, Clone)]
+pub enum FitVolume {
+    Aabb(Aabb),
+    Obb(Obb),
+    Sphere(BSphere),
+}
+
+/// The result of [best_fit]: the bounding volume with the smallest world space volume, and the
+/// volume of each kind of bounding volume relative to it. The ratio of the winning kind is 1.0, and
+/// the others are >= 1.0.
+#[derive(Debug, Clone)]
+pub struct BestFit {
+    pub volume: FitVolume,
+    pub aabb_ratio: f32,
+    pub obb_ratio: f32,
+    pub sphere_ratio: f32,
+}
+
+/// Computes an [Aabb], [Obb], and [BSphere] for the mesh, and returns the one with the smallest
+/// volume in world space. Near-spherical meshes tend to be best fit by the sphere, and elongated
+/// meshes that aren't aligned with the world axes by the OBB. If volumes are equal, the cheaper
+/// volume to update is preferred, in the order AABB, OBB, sphere.
+///
+/// This computes all three volumes, so it is meant as an analysis tool rather than something to
+/// run every frame.
+pub fn best_fit<'a>(
+    source: impl Into<VertexSource<'a>>,
+    transform: &GlobalTransform,
+) -> Result<BestFit, BoundingError> {
+    Ok(best_fit_vertices(&source.into().vertices()?, transform))
+}
+
+/// The [best_fit] of a set of vertices, such as those read from a [VertexSource].
+pub fn best_fit_vertices(vertices: &[Vec3], transform: &GlobalTransform) -> BestFit {
+    let aabb = Aabb::from_vertices(vertices, transform);
+    let obb = Obb::from_vertices(vertices, transform);
+    let sphere = BSphere::from_vertices(vertices, transform);
+    let aabb_volume = aabb.volume();
+    let obb_volume = obb.volume(transform);
+    let sphere_volume = sphere.volume(transform);
+    let best_volume = aabb_volume.min(obb_volume).min(sphere_volume);
+    let ratio = |volume: f32| {
+        if best_volume > 0.0 {
+            volume / best_volume
+        } else {
+            1.0
+        }
+    };
+    BestFit {
+        aabb_ratio: ratio(aabb_volume),
+        obb_ratio: ratio(obb_volume),
+        sphere_ratio: ratio(sphere_volume),
+        volume: if aabb_volume <= best_volume {
+            FitVolume::Aabb(aabb)
+        } else if obb_volume <= best_volume {
+            FitVolume::Obb(obb)
+        } else {
+            FitVolume::Sphere(sphere)
+        },
+    }
+}
+
+/// A diagnostic plugin that logs, once for each entity bounded by an [Aabb], [Obb], or [BSphere],
+/// the volumes of all three kinds of bounding volume fit to its mesh, relative to the AABB. This
+/// helps choose which `BoundingVolumePlugin` to ship, but computes every kind of volume for each
+/// mesh, so it should only be added while profiling.
+#[derive(Default)]
+pub struct FitDiagnosticsPlugin;
+
+impl Plugin for FitDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_to_stage(
+            CoreStage::PostUpdate,
+            log_fit_comparison
+                .system()
+                .after(BoundingSystem::UpdateBounds),
+        );
+    }
+}
+
+/// Logs the [best_fit] of each newly bounded entity, see [FitDiagnosticsPlugin]. The fits are
+/// computed from the same vertices as the entity's bounding volumes, so they respect its
+/// [BoundsMeshes], [LockBoundsToHandle], [BoundsReferenceTransform], [BoundsSourceAttribute],
+/// [BoundsVertexRange], and [BoundsProxyPoints].
+#[allow(clippy::type_complexity)]
+pub fn log_fit_comparison(
+    meshes: Res<Assets<Mesh>>,
+    decoder: Option<Res<PositionDecoder>>,
+    query: Query<
+        (
+            Entity,
+            Option<&Handle<Mesh>>,
+            &GlobalTransform,
+            Option<&BoundsMeshes>,
+            Option<&BoundsSourceAttribute>,
+            Option<&BoundsVertexRange>,
+            Option<&BoundsProxyPoints>,
+            Option<&LockBoundsToHandle>,
+            Option<&BoundsReferenceTransform>,
+        ),
+        Or<(Added<Aabb>, Added<Obb>, Added<BSphere>)>,
+    >,
+) {
+    let default_decoder = PositionDecoder::default();
+    let decoder = decoder.as_deref().unwrap_or(&default_decoder);
+    for (entity, handle, transform, bounds_meshes, attribute, range, proxy, lock, reference) in
+        query.iter()
+    {
+        let transform = BoundsReferenceTransform::resolve(reference, transform);
+        let vertices = match bounds_meshes {
+            Some(bounds_meshes) => bounds_meshes.vertices(&meshes, decoder),
+            None => handle
+                .and_then(|handle| meshes.get(LockBoundsToHandle::resolve(lock, handle)))
+                .map(|mesh| {
+                    VertexSource::with_attribute(mesh, attribute)
+                        .with_range(range)
+                        .with_proxy(proxy)
+                        .with_decoder(decoder)
+                        .vertices()
+                }),
+        };
+        let fit = match vertices {
+            Some(Ok(vertices)) => best_fit_vertices(&vertices, transform),
+            Some(Err(err)) => {
+                error!("Could not compare fits for entity {:?}: {}", entity, err);
+                continue;
+            }
+            None => continue,
+        };
+        let kind = match fit.volume {
+            FitVolume::Aabb(_) => "AABB",
+            FitVolume::Obb(_) => "OBB",
+            FitVolume::Sphere(_) => "sphere",
+        };
+        info!(
+            "{:?}: aabb_vol=1.00 obb_vol={:.2} sphere_vol={:.2} (relative to the AABB), best \
+            fit: {}",
+            entity,
+            fit.obb_ratio / fit.aabb_ratio,
+            fit.sphere_ratio / fit.aabb_ratio,
+            kind
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use crate::BoundingError;
+
+/// Writes the floats into a buffer as consecutive little-endian `f32`s. The buffer length `B` is
+/// fixed by the signature of each caller, and must be `4 * N`.
+pub(crate) fn floats_to_bytes<const N: usize, const B: usize>(floats: [f32; N]) -> [u8; B] {
+    let mut bytes = [0; B];
+    for (chunk, float) in bytes.chunks_exact_mut(4).zip(floats.iter()) {
+        chunk.copy_from_slice(&float.to_le_bytes());
+    }
+    bytes
+}
+
+/// Reads consecutive little-endian `f32`s from a buffer written by [floats_to_bytes], failing if
+/// the buffer doesn't hold exactly `N` floats.
+pub(crate) fn floats_from_bytes<const N: usize>(bytes: &[u8]) -> Result<[f32; N], BoundingError> {
+    if bytes.len() != N * 4 {
+        return Err(BoundingError::InvalidByteLength {
+            expected: N * 4,
+            found: bytes.len(),
+        });
+    }
+    let mut floats = [0.0; N];
+    for (float, chunk) in floats.iter_mut().zip(bytes.chunks_exact(4)) {
+        *float = f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    }
+    Ok(floats)
+}
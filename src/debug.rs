@@ -1,70 +1,475 @@
-use crate::{aabb::Aabb, obb::Obb, sphere::BSphere, BoundingVolume};
-use bevy::{
-    prelude::*,
-    render::{mesh::Indices, pipeline::PrimitiveTopology},
+use crate::{
+    mesh::line_list_mesh,
+    obb::Obb,
+    sphere::{BSphere, SphereSupport},
+    vertex::{
+        BoundsMeshes, BoundsProxyPoints, BoundsSourceAttribute, BoundsVertexRange,
+        LockBoundsToHandle, PositionDecoder, VertexSource,
+    },
+    Bounded, BoundingVolume, ChosenFitMethod, DebugMeshOptions, DebugMeshTopology, DerivedFromAabb,
+    ManualBounds, UPDATE_EPSILON,
 };
+use bevy::{ecs::schedule::ShouldRun, prelude::*, render::camera::RenderLayers};
+use std::marker::PhantomData;
 
 /// Marks an entity that should have a mesh added as a child to represent the mesh's bounding volume.
 #[derive(Component)]
 pub struct DebugBounds;
 
+/// Sets the color of the debug mesh of an entity marked with [DebugBounds]. Defaults to green.
+#[derive(Debug, Clone, Copy, Component)]
+pub struct DebugBoundsColor(pub Color);
+
+impl Default for DebugBoundsColor {
+    fn default() -> Self {
+        DebugBoundsColor(Color::rgb(0.0, 1.0, 0.0))
+    }
+}
+
+/// Renders the debug mesh of an entity marked with [DebugBounds] as a solid, translucent volume
+/// instead of a wireframe. `alpha` is the opacity of the volume, from 0.0 to 1.0.
+#[derive(Debug, Clone, Copy, Component)]
+pub struct DebugSolid {
+    pub alpha: f32,
+}
+
+impl Default for DebugSolid {
+    fn default() -> Self {
+        DebugSolid { alpha: 0.25 }
+    }
+}
+
+/// The defaults for the materials of all debug meshes. Changes apply to existing debug meshes too.
+///
+/// ## Note
+/// Debug lines are always drawn one pixel wide: the renderer has no support for wide lines, so
+/// there is no line width setting.
+#[derive(Debug, Clone, Copy)]
+pub struct DebugBoundsConfig {
+    /// Renders debug meshes in a flat color when true, the default, or shaded by the scene's
+    /// lighting when false, so they read depth better.
+    pub unlit: bool,
+}
+
+impl Default for DebugBoundsConfig {
+    fn default() -> Self {
+        DebugBoundsConfig { unlit: true }
+    }
+}
+
+/// Excludes an entity marked with [DebugBounds] from [update_debug_mesh_visibility], so the
+/// visibility of its debug meshes is left to the user instead of following the entity's own
+/// [Visible] component.
+#[derive(Debug, Clone, Copy, Default, Component)]
+pub struct ManualDebugVisibility;
+
+/// Scales the debug mesh of an entity marked with [DebugBounds] about the center of the bounding
+/// volume by this factor, without affecting the bounding volume itself. A factor slightly above
+/// 1.0, such as 1.01, keeps the debug mesh from z-fighting with the surface of a tightly bounded
+/// mesh.
+#[derive(Debug, Clone, Copy, Component)]
+pub struct DebugBoundsInflate(pub f32);
+
+/// Restricts the debug meshes of an entity marked with [DebugBounds] to these [RenderLayers], so
+/// they are only seen by cameras on these layers, such as an editor overlay camera. By default,
+/// debug meshes are seen by every camera on the default layer.
+#[derive(Debug, Clone, Copy, Component)]
+pub struct DebugRenderLayers(pub RenderLayers);
+
+/// Adds a bounding volume of type T to an entity, along with a debug mesh of the given color, in a
+/// single `insert_bundle` call.
+#[derive(Bundle)]
+pub struct BoundedBundle<T: 'static + BoundingVolume + Send + Sync> {
+    pub bounded: Bounded<T>,
+    pub debug: DebugBounds,
+    pub color: DebugBoundsColor,
+}
+
+impl<T: 'static + BoundingVolume + Send + Sync> BoundedBundle<T> {
+    /// Creates a bundle that will generate a bounding volume with a debug mesh of this `color`.
+    pub fn new(color: Color) -> Self {
+        BoundedBundle {
+            bounded: Bounded::default(),
+            debug: DebugBounds,
+            color: DebugBoundsColor(color),
+        }
+    }
+}
+
+impl<T: 'static + BoundingVolume + Send + Sync> Default for BoundedBundle<T> {
+    fn default() -> Self {
+        BoundedBundle::new(DebugBoundsColor::default().0)
+    }
+}
+
+/// A plugin that only shows debug meshes for entities marked with the component `M`, such as the
+/// `Selected` or `Hovered` marker of an editor, so [DebugBounds] doesn't have to be added and
+/// removed by hand. [DebugBounds] is added to entities when they gain `M`, and removed when they
+/// lose it, which despawns their debug meshes. Entities that were given [DebugBounds] directly are
+/// left untouched.
+pub struct DebugSelectionPlugin<M: Component>(PhantomData<M>);
+
+impl<M: Component> Default for DebugSelectionPlugin<M> {
+    fn default() -> Self {
+        DebugSelectionPlugin(PhantomData)
+    }
+}
+
+impl<M: Component> Plugin for DebugSelectionPlugin<M> {
+    fn build(&self, app: &mut App) {
+        // The markers are synced in `Update`, so the changes are applied before the debug systems
+        // run in `PostUpdate`, where the removal of [DebugBounds] is detected.
+        app.add_system_to_stage(CoreStage::Update, sync_debug_selection::<M>.system());
+    }
+}
+
+/// Marks a [DebugBounds] that was added by the [DebugSelectionPlugin] for the selection component
+/// `M`, so it is only removed when `M` is.
+#[derive(Component)]
+pub struct DebugSelected<M: Component>(PhantomData<M>);
+
+/// Adds [DebugBounds] to entities marked with the selection component `M`, and removes it from
+/// entities that are no longer marked.
+#[allow(clippy::type_complexity)]
+pub fn sync_debug_selection<M: Component>(
+    mut commands: Commands,
+    selected: Query<Entity, (With<M>, Without<DebugBounds>)>,
+    deselected: Query<Entity, (With<DebugSelected<M>>, Without<M>)>,
+) {
+    for entity in selected.iter() {
+        commands
+            .entity(entity)
+            .insert(DebugBounds)
+            .insert(DebugSelected::<M>(PhantomData));
+    }
+    for entity in deselected.iter() {
+        commands
+            .entity(entity)
+            .remove::<DebugBounds>()
+            .remove::<DebugSelected<M>>();
+    }
+}
+
+/// Runs the debug systems only when the PBR renderer is present. This is checked every frame,
+/// not when the plugin is built, so the plugin can be added before or after `DefaultPlugins`.
+pub fn debug_renderer_present(materials: Option<Res<Assets<StandardMaterial>>>) -> ShouldRun {
+    if materials.is_some() {
+        ShouldRun::Yes
+    } else {
+        ShouldRun::No
+    }
+}
+
+/// Warns once at startup when the PBR renderer is missing, because no debug meshes will be shown.
+pub fn warn_missing_debug_renderer(materials: Option<Res<Assets<StandardMaterial>>>) {
+    if materials.is_none() {
+        warn!(
+            "Debug bounding volumes are disabled, because the PBR renderer is missing. Disable the \
+            `debug` feature in headless apps."
+        );
+    }
+}
+
+/// Adds [DebugBounds] to every entity whose bounding volume of type T was just computed, see
+/// [BoundingVolumePlugin::with_debug](crate::BoundingVolumePlugin::with_debug).
+pub fn debug_all_bounds<T: Component>(
+    mut commands: Commands,
+    query: Query<Entity, (Added<T>, Without<DebugBounds>)>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).insert(DebugBounds);
+    }
+}
+
 /// Marks the debug bounding volume mesh, which exists as a child of a [BoundingVolumeDebug] entity
 #[derive(Component)]
 pub struct DebugBoundsMesh;
 
+/// The bounding volume, the [DebugMeshOptions], and the rotation and scale of the
+/// [GlobalTransform], that the current debug mesh of an entity was generated from. The debug mesh
+/// is only regenerated when these differ, so debug meshes of static entities are nearly free.
+#[derive(Debug, Clone, Component)]
+pub struct DebugBoundsSnapshot<T: BoundingVolume> {
+    bound_vol: T,
+    options: DebugMeshOptions,
+    rotation: Quat,
+    scale: Vec3,
+}
+
+impl<T: BoundingVolume> DebugBoundsSnapshot<T> {
+    /// Returns true iff a debug mesh generated from this bounding volume and transform would be
+    /// the same as the current debug mesh. The rotation and scale are compared too, because some
+    /// debug meshes negate them.
+    fn matches(
+        &self,
+        bound_vol: &T,
+        options: &DebugMeshOptions,
+        transform: &GlobalTransform,
+    ) -> bool {
+        self.bound_vol.approx_eq(bound_vol, UPDATE_EPSILON)
+            && self.options == *options
+            && self.rotation == transform.rotation
+            && self.scale == transform.scale
+    }
+}
+
 /// Updates existing debug meshes, and creates new debug meshes on entities with a bounding volume
-/// component marked with [BoundingVolumeDebug] and no existing debug mesh.
-#[allow(clippy::type_complexity)]
+/// component marked with [BoundingVolumeDebug] and no existing debug mesh. Adding or removing
+/// [DebugSolid] switches the debug mesh between a wireframe and a solid volume.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 pub fn update_debug_meshes<T>(
     mut commands: Commands,
+    config: Res<DebugBoundsConfig>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut meshes: ResMut<Assets<Mesh>>,
+    changed_query: Query<
+        Entity,
+        (
+            Or<(
+                Changed<T>,
+                Changed<GlobalTransform>,
+                Added<DebugBounds>,
+                Changed<DebugBoundsInflate>,
+                Changed<DebugMeshOptions>,
+                Changed<DebugSolid>,
+            )>,
+            With<DebugBounds>,
+        ),
+    >,
+    removed_solid: RemovedComponents<DebugSolid>,
     query: Query<
-        (&'static GlobalTransform, &T, Entity, Option<&Children>),
-        (Changed<T>, With<DebugBounds>),
+        (
+            &'static GlobalTransform,
+            &T,
+            Option<&Children>,
+            Option<&DebugBoundsColor>,
+            Option<&DebugSolid>,
+            Option<&DebugBoundsInflate>,
+            Option<&DebugMeshOptions>,
+            Option<&DebugBoundsSnapshot<T>>,
+            Option<&DebugRenderLayers>,
+        ),
+        With<DebugBounds>,
     >,
     mut debug_mesh_query: Query<&mut Handle<Mesh>, With<DebugBoundsMesh>>,
 ) where
     T: 'static + BoundingVolume + Clone + Send + Sync + std::fmt::Debug + Component,
     Mesh: From<&'static T>,
 {
-    for (transform, bound_vol, entity, optional_children) in query.iter() {
-        let mut updated_existing_child = false;
-        if let Some(children) = optional_children {
-            for child in children.iter() {
-                if let Ok(mut mesh_handle) = debug_mesh_query.get_mut(*child) {
-                    let mesh = bound_vol.new_debug_mesh(transform);
-                    let new_handle = meshes.add(mesh);
-                    *mesh_handle = new_handle;
-                    updated_existing_child = true;
-                    break;
-                }
+    for entity in changed_query.iter().chain(removed_solid.iter()) {
+        let (
+            transform,
+            bound_vol,
+            optional_children,
+            optional_color,
+            optional_solid,
+            optional_inflate,
+            optional_options,
+            optional_snapshot,
+            optional_layers,
+        ) = match query.get(entity) {
+            Ok(item) => item,
+            Err(_) => continue,
+        };
+        let mut options = optional_options.copied().unwrap_or_default();
+        if optional_solid.is_some() {
+            options.topology = DebugMeshTopology::Triangles;
+        }
+        if let Some(inflate) = optional_inflate {
+            options.inflation = inflate.0;
+        }
+        if let Some(snapshot) = optional_snapshot {
+            if snapshot.matches(bound_vol, &options, transform) {
+                continue;
             }
         }
+        commands.entity(entity).insert(DebugBoundsSnapshot {
+            bound_vol: bound_vol.clone(),
+            options,
+            rotation: transform.rotation,
+            scale: transform.scale,
+        });
+        let mesh = match bound_vol.new_debug_mesh(transform, &options) {
+            Ok(mesh) => mesh,
+            Err(err) => {
+                error!(
+                    "Could not build the debug mesh of entity {:?}: {}",
+                    entity, err
+                );
+                continue;
+            }
+        };
+        let existing_child = optional_children.and_then(|children| {
+            children
+                .iter()
+                .copied()
+                .find(|child| debug_mesh_query.get_mut(*child).is_ok())
+        });
         // if the entity had a child, we don't need to create a new one
-        if !updated_existing_child {
-            let mesh_handle = meshes.add(bound_vol.new_debug_mesh(transform));
+        if let Some(child) = existing_child {
+            if let Ok(mut mesh_handle) = debug_mesh_query.get_mut(child) {
+                *mesh_handle = meshes.add(mesh);
+            }
+        } else {
+            let mesh_handle = meshes.add(mesh);
+            let material = materials.add(StandardMaterial {
+                base_color: debug_color(optional_color, optional_solid),
+                unlit: config.unlit,
+                ..Default::default()
+            });
             commands.entity(entity).with_children(|parent| {
-                parent
-                    .spawn_bundle(PbrBundle {
-                        mesh: mesh_handle,
-                        material: materials.add(StandardMaterial {
-                            base_color: Color::rgb(0.0, 1.0, 0.0),
-                            unlit: true,
-                            ..Default::default()
-                        }),
+                let mut child = parent.spawn_bundle(PbrBundle {
+                    mesh: mesh_handle,
+                    material,
+                    visible: Visible {
+                        is_transparent: optional_solid.is_some(),
                         ..Default::default()
-                    })
-                    .insert(DebugBoundsMesh);
+                    },
+                    ..Default::default()
+                });
+                child.insert(DebugBoundsMesh);
+                if let Some(layers) = optional_layers {
+                    child.insert(layers.0);
+                }
             });
         }
     }
 }
 
+/// The color of the debug mesh of an entity, with the opacity of its [DebugSolid] if it has one.
+fn debug_color(color: Option<&DebugBoundsColor>, solid: Option<&DebugSolid>) -> Color {
+    let mut color = color.copied().unwrap_or_default().0;
+    if let Some(solid) = solid {
+        color.set_a(solid.alpha);
+    }
+    color
+}
+
+/// Updates the material, transparency, and render layers of existing debug meshes when the
+/// [DebugBoundsColor], [DebugSolid], or [DebugRenderLayers] of their entity are changed or
+/// removed, or when the [DebugBoundsConfig] changes. This is shared by all bounding volume types,
+/// so it is only added to the app once.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn update_debug_appearance(
+    mut commands: Commands,
+    config: Res<DebugBoundsConfig>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    changed_query: Query<
+        Entity,
+        (
+            Or<(
+                Changed<DebugBoundsColor>,
+                Changed<DebugSolid>,
+                Changed<DebugRenderLayers>,
+            )>,
+            With<DebugBounds>,
+        ),
+    >,
+    removed_color: RemovedComponents<DebugBoundsColor>,
+    removed_solid: RemovedComponents<DebugSolid>,
+    removed_layers: RemovedComponents<DebugRenderLayers>,
+    query: Query<
+        (
+            Entity,
+            &Children,
+            Option<&DebugBoundsColor>,
+            Option<&DebugSolid>,
+            Option<&DebugRenderLayers>,
+        ),
+        With<DebugBounds>,
+    >,
+    mut debug_mesh_query: Query<(&Handle<StandardMaterial>, &mut Visible), With<DebugBoundsMesh>>,
+) {
+    let entities: Vec<Entity> = if config.is_changed() {
+        query.iter().map(|(entity, ..)| entity).collect()
+    } else {
+        changed_query
+            .iter()
+            .chain(removed_color.iter())
+            .chain(removed_solid.iter())
+            .chain(removed_layers.iter())
+            .collect()
+    };
+    for entity in entities {
+        let (_, children, optional_color, optional_solid, optional_layers) = match query.get(entity)
+        {
+            Ok(item) => item,
+            Err(_) => continue,
+        };
+        for &child in children.iter() {
+            if let Ok((material, mut visible)) = debug_mesh_query.get_mut(child) {
+                if let Some(material) = materials.get_mut(material) {
+                    material.base_color = debug_color(optional_color, optional_solid);
+                    material.unlit = config.unlit;
+                }
+                if visible.is_transparent != optional_solid.is_some() {
+                    visible.is_transparent = optional_solid.is_some();
+                }
+                match optional_layers {
+                    Some(layers) => commands.entity(child).insert(layers.0),
+                    None => commands.entity(child).remove::<RenderLayers>(),
+                };
+            }
+        }
+    }
+}
+
+/// Despawns every debug mesh child of entities that had their [DebugBounds] marker removed: the
+/// bounding volume meshes, [DebugObbAxes] axes, and [DebugSphereSupport] markers. This is shared by
+/// all bounding volume types, so it is only added to the app once.
+#[allow(clippy::type_complexity)]
+pub fn remove_stale_debug_meshes(
+    mut commands: Commands,
+    removed: RemovedComponents<DebugBounds>,
+    children_query: Query<&Children>,
+    debug_mesh_query: Query<
+        (),
+        Or<(
+            With<DebugBoundsMesh>,
+            With<DebugObbAxesMesh>,
+            With<DebugSphereSupportMesh>,
+        )>,
+    >,
+) {
+    for entity in removed.iter() {
+        if let Ok(children) = children_query.get(entity) {
+            for child in children.iter() {
+                if debug_mesh_query.get(*child).is_ok() {
+                    commands.entity(*child).despawn_recursive();
+                }
+            }
+        }
+    }
+}
+
+/// Removes the [DebugBoundsSnapshot] of entities that had their [DebugBounds] marker removed, so
+/// their debug mesh is created again if the marker is added back.
+pub fn remove_stale_debug_snapshots<T>(
+    mut commands: Commands,
+    removed: RemovedComponents<DebugBounds>,
+    snapshot_query: Query<(), With<DebugBoundsSnapshot<T>>>,
+) where
+    T: 'static + BoundingVolume + Send + Sync + Component,
+{
+    for entity in removed.iter() {
+        if snapshot_query.get(entity).is_ok() {
+            commands.entity(entity).remove::<DebugBoundsSnapshot<T>>();
+        }
+    }
+}
+
 #[allow(clippy::type_complexity)]
 pub fn update_debug_mesh_visibility<T>(
     mut query: QuerySet<(
-        QueryState<(&Children, &Visible), (With<DebugBounds>, With<T>, Changed<Visible>)>,
+        QueryState<
+            (&Children, &Visible),
+            (
+                With<DebugBounds>,
+                With<T>,
+                Changed<Visible>,
+                Without<ManualDebugVisibility>,
+            ),
+        >,
         QueryState<&mut Visible, With<DebugBoundsMesh>>,
     )>,
 ) where
@@ -84,135 +489,249 @@ pub fn update_debug_mesh_visibility<T>(
     }
 }
 
-impl From<&Aabb> for Mesh {
-    fn from(aabb: &Aabb) -> Self {
-        /*
-              (2)-----(3)               Y
-               | \     | \              |
-               |  (1)-----(0) MAX       o---X
-               |   |   |   |             \
-          MIN (6)--|--(7)  |              Z
-                 \ |     \ |
-                  (5)-----(4)
-        */
-        let vertices: Vec<[f32; 3]> = aabb
-            .vertices_mesh_space()
-            .iter()
-            .map(|vert| [vert.x, vert.y, vert.z])
-            .collect();
-
-        let indices = Indices::U32(vec![
-            0, 1, 1, 2, 2, 3, 3, 0, // Top ring
-            4, 5, 5, 6, 6, 7, 7, 4, // Bottom ring
-            0, 4, 1, 5, 2, 6, 3, 7, // Verticals
-        ]);
-
-        let mut mesh = Mesh::new(PrimitiveTopology::LineList);
-        mesh.set_attribute(Mesh::ATTRIBUTE_POSITION, vertices.clone());
-        mesh.set_attribute(Mesh::ATTRIBUTE_UV_0, vertices.clone());
-        mesh.set_attribute(Mesh::ATTRIBUTE_NORMAL, vertices);
-        mesh.set_indices(Some(indices));
-        mesh
-    }
-}
-
-impl From<&Obb> for Mesh {
-    fn from(obb: &Obb) -> Self {
-        /*
-              (2)-----(3)               Y
-               | \     | \              |
-               |  (1)-----(0) MAX       o---X
-               |   |   |   |             \
-          MIN (6)--|--(7)  |              Z
-                 \ |     \ |
-                  (5)-----(4)
-        */
-        let vertices: Vec<[f32; 3]> = obb
-            .vertices_mesh_space()
-            .iter()
-            .map(|vert| [vert.x, vert.y, vert.z])
-            .collect();
-
-        let indices = Indices::U32(vec![
-            0, 1, 1, 2, 2, 3, 3, 0, // Top ring
-            4, 5, 5, 6, 6, 7, 7, 4, // Bottom ring
-            0, 4, 1, 5, 2, 6, 3, 7, // Verticals
-        ]);
-
-        let mut mesh = Mesh::new(PrimitiveTopology::LineList);
-        mesh.set_attribute(Mesh::ATTRIBUTE_POSITION, vertices.clone());
-        mesh.set_attribute(Mesh::ATTRIBUTE_UV_0, vertices.clone());
-        mesh.set_attribute(Mesh::ATTRIBUTE_NORMAL, vertices);
-        mesh.set_indices(Some(indices));
-        mesh
-    }
-}
-
-impl From<&BSphere> for Mesh {
-    fn from(sphere: &BSphere) -> Self {
-        let radius = sphere.mesh_space_radius();
-        let origin = sphere.mesh_space_origin();
-        let n_points: i8 = 24;
-        let vertices_x0: Vec<[f32; 3]> = (0..n_points)
-            .map(|i| {
-                let angle = i as f32 * 2.0 * std::f32::consts::PI / (n_points as f32);
-                [
-                    0.0,
-                    angle.sin() * radius + origin.y,
-                    angle.cos() * radius + origin.z,
-                ]
-            })
-            .collect();
-        let vertices_y0: Vec<[f32; 3]> = (0..n_points)
-            .map(|i| {
-                let angle = i as f32 * 2.0 * std::f32::consts::PI / (n_points as f32);
-                [
-                    angle.cos() * radius + origin.x,
-                    0.0,
-                    angle.sin() * radius + origin.z,
-                ]
-            })
-            .collect();
-        let vertices_z0: Vec<[f32; 3]> = (0..n_points)
-            .map(|i| {
-                let angle = i as f32 * 2.0 * std::f32::consts::PI / (n_points as f32);
-                [
-                    angle.cos() * radius + origin.x,
-                    angle.sin() * radius + origin.y,
-                    0.0,
-                ]
-            })
-            .collect();
-        let vertices = [vertices_x0, vertices_y0, vertices_z0].concat();
-        let indices_single: Vec<u32> = (0..n_points * 2)
-            .map(|i| {
-                let result = (i as u32 + 1) / 2;
-                if result == n_points as u32 {
-                    0
-                } else {
-                    result
+/// Marks an entity with an [Obb] and [DebugBounds] to also have the axes of its oriented bounding
+/// box drawn, as lines from the center of the box to its faces: X in red, Y in green, Z in blue.
+/// This is useful to visually verify the result of [Obb::orientation].
+#[derive(Component)]
+pub struct DebugObbAxes;
+
+/// Marks one of the axis line meshes of a [DebugObbAxes] entity, storing the index of the axis.
+#[derive(Component)]
+pub struct DebugObbAxesMesh(usize);
+
+/// Updates existing axis line meshes, and creates new ones on entities with an [Obb] marked with
+/// [DebugBounds] and [DebugObbAxes] and no existing axis line meshes. The axes span the box after
+/// [DebugBoundsInflate], like its debug mesh.
+#[allow(clippy::type_complexity)]
+pub fn update_debug_obb_axes(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    query: Query<
+        (
+            &Obb,
+            Entity,
+            Option<&Children>,
+            Option<&DebugRenderLayers>,
+            Option<&DebugBoundsInflate>,
+        ),
+        (
+            With<DebugObbAxes>,
+            With<DebugBounds>,
+            Or<(
+                Changed<Obb>,
+                Added<DebugObbAxes>,
+                Added<DebugBounds>,
+                Changed<DebugBoundsInflate>,
+            )>,
+        ),
+    >,
+    mut axes_mesh_query: Query<(&mut Handle<Mesh>, &DebugObbAxesMesh)>,
+) {
+    for (obb, entity, optional_children, optional_layers, optional_inflate) in query.iter() {
+        let obb = &match optional_inflate {
+            Some(inflate) => obb.inflated(inflate.0),
+            None => obb.clone(),
+        };
+        let mut updated_existing_children = false;
+        if let Some(children) = optional_children {
+            for child in children.iter() {
+                if let Ok((mut mesh_handle, axis)) = axes_mesh_query.get_mut(*child) {
+                    *mesh_handle = meshes.add(obb_axis_mesh(obb, axis.0));
+                    updated_existing_children = true;
                 }
-            })
-            .collect();
-        let indices = Indices::U32(
-            [
-                indices_single
-                    .iter()
-                    .map(|&index| index + n_points as u32)
-                    .collect(),
-                indices_single
-                    .iter()
-                    .map(|&index| index + 2 * n_points as u32)
-                    .collect(),
-                indices_single,
-            ]
-            .concat(),
-        );
-        let mut mesh = Mesh::new(PrimitiveTopology::LineList);
-        mesh.set_attribute(Mesh::ATTRIBUTE_POSITION, vertices.clone());
-        mesh.set_attribute(Mesh::ATTRIBUTE_UV_0, vertices.clone());
-        mesh.set_attribute(Mesh::ATTRIBUTE_NORMAL, vertices);
-        mesh.set_indices(Some(indices));
-        mesh
+            }
+        }
+        if !updated_existing_children {
+            let colors = [
+                Color::rgb(1.0, 0.0, 0.0),
+                Color::rgb(0.0, 1.0, 0.0),
+                Color::rgb(0.0, 0.0, 1.0),
+            ];
+            for (axis, color) in colors.iter().enumerate() {
+                let mesh_handle = meshes.add(obb_axis_mesh(obb, axis));
+                let material = materials.add(StandardMaterial {
+                    base_color: *color,
+                    unlit: true,
+                    ..Default::default()
+                });
+                commands.entity(entity).with_children(|parent| {
+                    let mut child = parent.spawn_bundle(PbrBundle {
+                        mesh: mesh_handle,
+                        material,
+                        ..Default::default()
+                    });
+                    child.insert(DebugObbAxesMesh(axis));
+                    if let Some(layers) = optional_layers {
+                        child.insert(layers.0);
+                    }
+                });
+            }
+        }
+    }
+}
+
+/// Builds a line mesh, in mesh space, from the center of the [Obb] to the face of the box along
+/// the box axis with the given index.
+fn obb_axis_mesh(obb: &Obb, axis: usize) -> Mesh {
+    let aabb = obb.mesh_aabb();
+    let center = (aabb.minimums() + aabb.maximums()) * 0.5;
+    let mut face = center;
+    face[axis] = aabb.maximums()[axis];
+    line_list_mesh(&[(
+        obb.orientation().mul_vec3(center),
+        obb.orientation().mul_vec3(face),
+    )])
+}
+
+/// Marks an entity with a [BSphere] and [DebugBounds] to also have the [SphereSupport] points of
+/// its sphere fit stored on the entity, and drawn as small yellow crosses. This repeats the fit
+/// that produced the sphere, from the same vertices, whenever the sphere changes, so it should
+/// only be used to diagnose unexpectedly large spheres. Spheres supplied with [ManualBounds]
+/// weren't fit, so they have no support.
+#[derive(Component)]
+pub struct DebugSphereSupport;
+
+/// Marks the mesh of the support point markers of a [DebugSphereSupport] entity.
+#[derive(Component)]
+pub struct DebugSphereSupportMesh;
+
+/// Updates the [SphereSupport] and its marker mesh, and creates a new marker mesh, on entities with
+/// a [BSphere] marked with [DebugBounds] and [DebugSphereSupport]. The support is found with the
+/// same vertices and fit as the sphere: the [BoundsMeshes] or the resolved mesh, the
+/// [ChosenFitMethod], or the box diagonal of a sphere that was [DerivedFromAabb].
+#[allow(clippy::type_complexity)]
+pub fn update_debug_sphere_support(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    decoder: Res<PositionDecoder>,
+    query: Query<
+        (
+            (
+                &BSphere,
+                Entity,
+                Option<&Children>,
+                Option<&DebugRenderLayers>,
+            ),
+            (
+                Option<&Handle<Mesh>>,
+                Option<&LockBoundsToHandle>,
+                Option<&BoundsMeshes>,
+                Option<&BoundsSourceAttribute>,
+                Option<&BoundsVertexRange>,
+                Option<&BoundsProxyPoints>,
+            ),
+            Option<&ChosenFitMethod<BSphere>>,
+            Option<&DerivedFromAabb<BSphere>>,
+        ),
+        (
+            With<DebugSphereSupport>,
+            With<DebugBounds>,
+            Without<DebugSphereSupportMesh>,
+            Without<ManualBounds>,
+            Or<(
+                Changed<BSphere>,
+                Added<DebugSphereSupport>,
+                Added<DebugBounds>,
+            )>,
+        ),
+    >,
+    mut support_mesh_query: Query<&mut Handle<Mesh>, With<DebugSphereSupportMesh>>,
+) {
+    for (
+        (sphere, entity, optional_children, optional_layers),
+        (handle, lock, bounds_meshes, attribute, range, proxy),
+        chosen_method,
+        derived,
+    ) in query.iter()
+    {
+        let support = match derived {
+            // The box was fit to the vertices, but its diagonal determined the sphere.
+            Some(derived) => SphereSupport {
+                poles: derived.diagonal,
+                last_grown_to: None,
+            },
+            None => {
+                let vertices = match (bounds_meshes, handle) {
+                    (Some(bounds_meshes), _) => match bounds_meshes.vertices(&meshes, &decoder) {
+                        Some(vertices) => vertices,
+                        None => continue,
+                    },
+                    (None, Some(handle)) => {
+                        match meshes.get(LockBoundsToHandle::resolve(lock, handle)) {
+                            Some(mesh) => VertexSource::with_attribute(mesh, attribute)
+                                .with_range(range)
+                                .with_proxy(proxy)
+                                .with_decoder(&decoder)
+                                .vertices(),
+                            None => continue,
+                        }
+                    }
+                    (None, None) => continue,
+                };
+                match vertices {
+                    Ok(vertices) => {
+                        let method = chosen_method.map(|chosen_method| chosen_method.method);
+                        BSphere::from_vertices_with_method_and_support(&vertices, method).1
+                    }
+                    Err(err) => {
+                        error!("Could not find the sphere support of {:?}: {}", entity, err);
+                        continue;
+                    }
+                }
+            }
+        };
+        let marker_mesh = sphere_support_mesh(&support, *sphere.mesh_space_radius() * 0.05);
+        commands.entity(entity).insert(support);
+        let mut updated_existing_child = false;
+        if let Some(children) = optional_children {
+            for child in children.iter() {
+                if let Ok(mut mesh_handle) = support_mesh_query.get_mut(*child) {
+                    *mesh_handle = meshes.add(marker_mesh.clone());
+                    updated_existing_child = true;
+                    break;
+                }
+            }
+        }
+        if !updated_existing_child {
+            let mesh_handle = meshes.add(marker_mesh);
+            let material = materials.add(StandardMaterial {
+                base_color: Color::rgb(1.0, 1.0, 0.0),
+                unlit: true,
+                ..Default::default()
+            });
+            commands.entity(entity).with_children(|parent| {
+                let mut child = parent.spawn_bundle(PbrBundle {
+                    mesh: mesh_handle,
+                    material,
+                    ..Default::default()
+                });
+                child.insert(DebugSphereSupportMesh);
+                if let Some(layers) = optional_layers {
+                    child.insert(layers.0);
+                }
+            });
+        }
     }
 }
+
+/// Builds a line mesh, in mesh space, with a cross of the given `size` at each support point.
+fn sphere_support_mesh(support: &SphereSupport, size: f32) -> Mesh {
+    let points = [
+        Some(support.poles.0),
+        Some(support.poles.1),
+        support.last_grown_to,
+    ];
+    let edges: Vec<(Vec3, Vec3)> = points
+        .iter()
+        .flatten()
+        .flat_map(|point| {
+            [Vec3::X, Vec3::Y, Vec3::Z]
+                .iter()
+                .map(|axis| (*point - *axis * size, *point + *axis * size))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    line_list_mesh(&edges)
+}
@@ -0,0 +1,98 @@
+use crate::{BoundingVolume, CullResult};
+use bevy::prelude::*;
+
+/// The six planes of a camera's view frustum, in world space, for culling bounding volumes. Each
+/// plane is stored as a `(point, normal)` pair with the normal pointing out of the frustum, in the
+/// order left, right, bottom, top, near, far.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frustum {
+    planes: [(Vec3, Vec3); 6],
+}
+
+impl Frustum {
+    /// Extracts the frustum planes from a combined view-projection matrix, which maps world space
+    /// to clip space with a depth range of 0 to 1, as used by Bevy's projections. This works for
+    /// both perspective and orthographic projections.
+    pub fn from_view_projection(view_projection: &Mat4) -> Frustum {
+        let rows = view_projection.transpose();
+        let (row_x, row_y, row_z, row_w) = (rows.x_axis, rows.y_axis, rows.z_axis, rows.w_axis);
+        // Each plane (a, b, c, d) contains the points where a*x + b*y + c*z + d >= 0, so the
+        // normal (a, b, c) points into the frustum. The depth range starts at 0, so the near plane
+        // is just the z row, not `w + z` as with an OpenGL style -1 to 1 depth range.
+        let inward_planes = [
+            row_w + row_x,
+            row_w - row_x,
+            row_w + row_y,
+            row_w - row_y,
+            row_z,
+            row_w - row_z,
+        ];
+        let mut planes = [(Vec3::ZERO, Vec3::ZERO); 6];
+        for (plane, inward) in planes.iter_mut().zip(inward_planes.iter()) {
+            let normal = inward.truncate();
+            let length = normal.length();
+            let point = -normal * inward.w / (length * length);
+            *plane = (point, -normal / length);
+        }
+        Frustum { planes }
+    }
+
+    /// Builds the frustum of a perspective camera at `camera_transform`, looking down its local -Z
+    /// axis. `fov` is the vertical field of view in radians, and `aspect_ratio` is width / height,
+    /// matching Bevy's `PerspectiveProjection`.
+    pub fn from_perspective(
+        camera_transform: &GlobalTransform,
+        fov: f32,
+        aspect_ratio: f32,
+        near: f32,
+        far: f32,
+    ) -> Frustum {
+        let projection = Mat4::perspective_rh(fov, aspect_ratio, near, far);
+        Frustum::from_view_projection(&(projection * camera_transform.compute_matrix().inverse()))
+    }
+
+    /// Builds the frustum of an orthographic camera at `camera_transform`, looking down its local
+    /// -Z axis. The extents of the view volume are given in camera space, matching Bevy's
+    /// `OrthographicProjection`.
+    pub fn from_orthographic(
+        camera_transform: &GlobalTransform,
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near: f32,
+        far: f32,
+    ) -> Frustum {
+        let projection = Mat4::orthographic_rh(left, right, bottom, top, near, far);
+        Frustum::from_view_projection(&(projection * camera_transform.compute_matrix().inverse()))
+    }
+
+    /// Returns the `(point, normal)` planes of the frustum, with normals pointing outward, in the
+    /// order left, right, bottom, top, near, far.
+    pub fn planes(&self) -> &[(Vec3, Vec3); 6] {
+        &self.planes
+    }
+
+    /// Classifies a bounding volume against the planes of this frustum.
+    pub fn classify<T: BoundingVolume>(
+        &self,
+        bound_vol: &T,
+        bound_vol_position: &GlobalTransform,
+    ) -> CullResult {
+        bound_vol.classify_against_planes(bound_vol_position, &self.planes)
+    }
+
+    /// Returns true iff the bounding volume is not entirely outside of any of the frustum planes.
+    /// This is conservative: a volume near a corner of the frustum may be reported as visible even
+    /// though it is outside of the frustum.
+    pub fn contains<T: BoundingVolume>(
+        &self,
+        bound_vol: &T,
+        bound_vol_position: &GlobalTransform,
+    ) -> bool {
+        !matches!(
+            self.classify(bound_vol, bound_vol_position),
+            CullResult::Culled { .. }
+        )
+    }
+}
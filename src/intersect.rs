@@ -0,0 +1,295 @@
+//! Stateless intersection tests between bounding volumes and world space primitives. Each volume is
+//! passed with the [GlobalTransform] of its mesh, so these work just as well with volumes computed
+//! ad hoc as with volumes stored in components. The intersection methods on the bounding volume
+//! types are thin wrappers over these functions.
+
+use crate::{aabb::Aabb, obb::Obb, sphere::BSphere, BoundingVolume};
+use bevy::prelude::*;
+
+/// Returns true iff the bounding boxes `a` and `b` overlap in world space.
+pub fn aabb_aabb(
+    a: &Aabb,
+    a_transform: &GlobalTransform,
+    b: &Aabb,
+    b_transform: &GlobalTransform,
+) -> bool {
+    let a = a.world_aabb(a_transform);
+    let b = b.world_aabb(b_transform);
+    a.minimums().cmple(b.maximums()).all() && b.minimums().cmple(a.maximums()).all()
+}
+
+/// Returns true iff the bounding spheres `a` and `b` overlap in world space.
+pub fn sphere_sphere(
+    a: &BSphere,
+    a_transform: &GlobalTransform,
+    b: &BSphere,
+    b_transform: &GlobalTransform,
+) -> bool {
+    let radii = a.radius(a_transform) + b.radius(b_transform);
+    a.origin(*a_transform)
+        .distance_squared(b.origin(*b_transform))
+        <= radii * radii
+}
+
+/// Returns true iff the oriented bounding boxes `a` and `b` overlap in world space. This is the
+/// separating axis test over the 15 candidate axes of a pair of boxes: the face normals of each
+/// box, and the cross products of their edge directions.
+pub fn obb_obb(
+    a: &Obb,
+    a_transform: &GlobalTransform,
+    b: &Obb,
+    b_transform: &GlobalTransform,
+) -> bool {
+    boxes_intersect(&a.vertices(*a_transform), &b.vertices(*b_transform))
+}
+
+/// Returns true iff the bounding box and the oriented bounding box overlap in world space, with the
+/// same separating axis test as [obb_obb].
+pub fn aabb_obb(
+    aabb: &Aabb,
+    aabb_transform: &GlobalTransform,
+    obb: &Obb,
+    obb_transform: &GlobalTransform,
+) -> bool {
+    boxes_intersect(
+        &aabb.vertices(*aabb_transform),
+        &obb.vertices(*obb_transform),
+    )
+}
+
+/// Returns true iff the bounding box and the bounding sphere overlap in world space.
+pub fn aabb_sphere(
+    aabb: &Aabb,
+    aabb_transform: &GlobalTransform,
+    sphere: &BSphere,
+    sphere_transform: &GlobalTransform,
+) -> bool {
+    let aabb = aabb.world_aabb(aabb_transform);
+    let center = sphere.origin(*sphere_transform);
+    let closest = center.max(aabb.minimums()).min(aabb.maximums());
+    let radius = sphere.radius(sphere_transform);
+    closest.distance_squared(center) <= radius * radius
+}
+
+/// Returns true iff the oriented bounding box and the bounding sphere overlap in world space. Like
+/// [Obb::isometry], this is only exact for a uniform scale of the box.
+pub fn obb_sphere(
+    obb: &Obb,
+    obb_transform: &GlobalTransform,
+    sphere: &BSphere,
+    sphere_transform: &GlobalTransform,
+) -> bool {
+    let (center, rotation, half_extents) = obb.isometry(obb_transform);
+    // The center of the sphere in the frame of the box, where the box is axis aligned.
+    let local = rotation.inverse() * (sphere.origin(*sphere_transform) - center);
+    let closest = local.max(-half_extents).min(half_extents);
+    let radius = sphere.radius(sphere_transform);
+    closest.distance_squared(local) <= radius * radius
+}
+
+/// Separating axis test of two boxes, given their world space vertices in the order of
+/// [Aabb::vertices_mesh_space].
+fn boxes_intersect(vertices: &[Vec3; 8], other_vertices: &[Vec3; 8]) -> bool {
+    // The edge directions are taken from the world space vertices, so they stay correct when the
+    // transform has a non-uniform scale.
+    let edge_directions = |vertices: &[Vec3; 8]| {
+        [
+            vertices[0] - vertices[1],
+            vertices[0] - vertices[4],
+            vertices[0] - vertices[3],
+        ]
+    };
+    let edges = edge_directions(vertices);
+    let other_edges = edge_directions(other_vertices);
+    let face_normals = |edges: &[Vec3; 3]| {
+        [
+            separating_axis(edges[1], edges[2]),
+            separating_axis(edges[2], edges[0]),
+            separating_axis(edges[0], edges[1]),
+        ]
+    };
+    let mut axes: Vec<Option<Vec3>> = face_normals(&edges).to_vec();
+    axes.extend_from_slice(&face_normals(&other_edges));
+    for edge in edges.iter() {
+        for other_edge in other_edges.iter() {
+            axes.push(separating_axis(*edge, *other_edge));
+        }
+    }
+    !axes.iter().flatten().any(|&axis| {
+        let (minimum, maximum) = project_vertices(vertices, axis);
+        let (other_minimum, other_maximum) = project_vertices(other_vertices, axis);
+        maximum < other_minimum || other_maximum < minimum
+    })
+}
+
+/// The candidate separating axis `a × b`, or `None` if `a` and `b` are parallel or either has no
+/// length, in which case the axis can't separate anything. Parallelism is judged relative to the
+/// lengths of `a` and `b`, so the result doesn't depend on the scale of the shapes being tested.
+pub(crate) fn separating_axis(a: Vec3, b: Vec3) -> Option<Vec3> {
+    let axis = a.cross(b);
+    if axis.length_squared() <= f32::EPSILON * a.length_squared() * b.length_squared() {
+        None
+    } else {
+        Some(axis)
+    }
+}
+
+/// Returns true iff the line segment from `a` to `b`, in world space, intersects the bounding box.
+/// This includes the case where the segment lies entirely inside the box.
+pub fn segment_aabb(aabb: &Aabb, transform: &GlobalTransform, a: Vec3, b: Vec3) -> bool {
+    segment_intersects_extents(
+        transform.translation + aabb.minimums(),
+        transform.translation + aabb.maximums(),
+        a,
+        b,
+    )
+}
+
+/// Returns true iff the line segment from `a` to `b`, in world space, intersects the oriented
+/// bounding box. This includes the case where the segment lies entirely inside the box.
+pub fn segment_obb(obb: &Obb, transform: &GlobalTransform, a: Vec3, b: Vec3) -> bool {
+    // Move the segment into the space of the mesh-space AABB, where the test is axis aligned. This
+    // is an affine transform, so the segment parameterization is unchanged.
+    let world_to_box = (transform.compute_matrix() * Mat4::from_quat(obb.orientation())).inverse();
+    segment_intersects_extents(
+        obb.mesh_aabb().minimums(),
+        obb.mesh_aabb().maximums(),
+        world_to_box.transform_point3(a),
+        world_to_box.transform_point3(b),
+    )
+}
+
+/// Returns true iff the line segment from `a` to `b`, in world space, intersects the bounding
+/// sphere. This includes the case where the segment lies entirely inside the sphere.
+pub fn segment_sphere(sphere: &BSphere, transform: &GlobalTransform, a: Vec3, b: Vec3) -> bool {
+    let origin = sphere.origin(*transform);
+    let direction = b - a;
+    let length_squared = direction.length_squared();
+    // Find the point on the segment closest to the sphere origin
+    let t = if length_squared < f32::EPSILON {
+        0.0
+    } else {
+        ((origin - a).dot(direction) / length_squared).clamp(0.0, 1.0)
+    };
+    (a + direction * t).distance(origin) <= sphere.radius(transform)
+}
+
+/// Casts the ray from `origin` along `direction`, in world space, against the bounding box, and
+/// returns the ray parameter `t` at which it enters the box, so the hit is at
+/// `origin + direction * t`, or `None` if it misses. A ray starting inside of the box returns 0.
+pub fn ray_aabb(
+    aabb: &Aabb,
+    transform: &GlobalTransform,
+    origin: Vec3,
+    direction: Vec3,
+) -> Option<f32> {
+    slab_entry(
+        transform.translation + aabb.minimums(),
+        transform.translation + aabb.maximums(),
+        origin,
+        direction,
+        f32::INFINITY,
+    )
+}
+
+/// Returns true iff the sphere intersects the cone with its tip at `apex`, opening along `dir` with
+/// the given `half_angle` in radians, and capped at `range` along `dir`, such as the volume lit by
+/// a spotlight. A sphere that contains the apex always intersects the cone.
+pub fn sphere_cone(
+    sphere: &BSphere,
+    transform: &GlobalTransform,
+    apex: Vec3,
+    dir: Vec3,
+    half_angle: f32,
+    range: f32,
+) -> bool {
+    sphere_intersects_cone(
+        sphere.origin(*transform),
+        sphere.radius(transform),
+        apex,
+        dir,
+        half_angle,
+        range,
+    )
+}
+
+/// Slab test of the segment from `a` to `b` against the axis-aligned box spanning `minimums` to
+/// `maximums`, with all arguments in the same coordinate space. The segment parameter `t` is
+/// clamped to `[0, 1]`, so the segment only intersects if the box lies between its endpoints.
+pub(crate) fn segment_intersects_extents(minimums: Vec3, maximums: Vec3, a: Vec3, b: Vec3) -> bool {
+    segment_entry_extents(minimums, maximums, a, b).is_some()
+}
+
+/// Returns the segment parameter `t` in `[0, 1]` at which the segment from `a` to `b` enters the
+/// axis-aligned box spanning `minimums` to `maximums`, or `None` if it misses the box. If `a` is
+/// inside of the box, this is 0.
+pub(crate) fn segment_entry_extents(
+    minimums: Vec3,
+    maximums: Vec3,
+    a: Vec3,
+    b: Vec3,
+) -> Option<f32> {
+    slab_entry(minimums, maximums, a, b - a, 1.0)
+}
+
+/// Slab test of the line `origin + direction * t`, for `t` in `[0, t_max]`, against the
+/// axis-aligned box spanning `minimums` to `maximums`. Returns the smallest `t` inside of the box.
+fn slab_entry(
+    minimums: Vec3,
+    maximums: Vec3,
+    origin: Vec3,
+    direction: Vec3,
+    t_max: f32,
+) -> Option<f32> {
+    let mut t_min = 0.0_f32;
+    let mut t_max = t_max;
+    for axis in 0..3 {
+        if direction[axis].abs() < f32::EPSILON {
+            // The line is parallel to this slab, so it must start inside of it.
+            if origin[axis] < minimums[axis] || origin[axis] > maximums[axis] {
+                return None;
+            }
+        } else {
+            let t_0 = (minimums[axis] - origin[axis]) / direction[axis];
+            let t_1 = (maximums[axis] - origin[axis]) / direction[axis];
+            t_min = t_min.max(t_0.min(t_1));
+            t_max = t_max.min(t_0.max(t_1));
+            if t_min > t_max {
+                return None;
+            }
+        }
+    }
+    Some(t_min)
+}
+
+/// Returns the minimum and maximum of the projections of the vertices onto `axis`.
+pub(crate) fn project_vertices(vertices: &[Vec3], axis: Vec3) -> (f32, f32) {
+    vertices
+        .iter()
+        .fold((f32::MAX, f32::MIN), |(minimum, maximum), vertex| {
+            let projection = vertex.dot(axis);
+            (minimum.min(projection), maximum.max(projection))
+        })
+}
+
+/// Tests a sphere in world space against a cone, see [BSphere::intersects_cone].
+pub(crate) fn sphere_intersects_cone(
+    center: Vec3,
+    radius: f32,
+    apex: Vec3,
+    dir: Vec3,
+    half_angle: f32,
+    range: f32,
+) -> bool {
+    let to_center = center - apex;
+    if to_center.length_squared() <= radius * radius {
+        return true;
+    }
+    let dir = dir.normalize();
+    // Distance from the apex to the center, along and perpendicular to the cone axis.
+    let axial = to_center.dot(dir);
+    let radial = (to_center.length_squared() - axial * axial).max(0.0).sqrt();
+    // Signed distance from the center to the surface of the cone, positive outside.
+    let cone_distance = half_angle.cos() * radial - half_angle.sin() * axial;
+    cone_distance <= radius && axial <= range + radius && axial >= -radius
+}
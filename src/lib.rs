@@ -1,33 +1,146 @@
 pub mod aabb;
+pub mod best_fit;
+mod bytes;
+#[cfg(feature = "debug")]
 pub mod debug;
+pub mod frustum;
+pub mod intersect;
+mod mesh;
 pub mod obb;
+pub mod scene;
 pub mod sphere;
+pub mod vertex;
 
-use bevy::{prelude::*, transform::TransformSystem};
-use debug::{update_debug_mesh_visibility, update_debug_meshes};
+use aabb::Aabb;
+use bevy::{
+    asset::HandleId,
+    prelude::*,
+    tasks::{ComputeTaskPool, TaskPool},
+    transform::TransformSystem,
+    utils::{HashMap, HashSet},
+};
+#[cfg(feature = "debug")]
+use debug::{
+    debug_all_bounds, debug_renderer_present, remove_stale_debug_meshes,
+    remove_stale_debug_snapshots, update_debug_appearance, update_debug_mesh_visibility,
+    update_debug_meshes, warn_missing_debug_renderer,
+};
+use scene::{update_scene_bounds, SceneBounds};
 use std::fmt::Debug;
 use std::marker::PhantomData;
+use std::{borrow::Cow, ops::Range};
+use vertex::{
+    bulk_vertices, clear_vertex_cache, invalidate_shared_vertices, stale_meshes, BoundsMeshes,
+    BoundsProxyPoints, BoundsSourceAttribute, BoundsVertexRange, LockBoundsToHandle,
+    MeshVertexCache, PositionDecoder, SharedVertexCache, VertexSource, VertexSourceKey,
+};
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
 pub enum BoundingSystem {
+    Spawn,
     UpdateBounds,
+    /// The early bounds update in [CoreStage::Update], added with
+    /// [BoundingVolumePlugin::with_early_sync]. Systems that need this frame's bounds in
+    /// [CoreStage::Update] should run after this label.
+    SyncBounds,
     UpdateDebug,
     UpdateDebugVisibility,
 }
 
 #[derive(Default)]
 pub struct BoundingVolumePlugin<T: BoundingVolume> {
+    fit_budget: Option<usize>,
+    early_sync: bool,
+    #[cfg(feature = "debug")]
+    debug: bool,
     marker: std::marker::PhantomData<T>,
 }
 
+impl<T: BoundingVolume> BoundingVolumePlugin<T> {
+    /// Creates the plugin with a [FitBudget]: meshes with fewer than `fit_budget` vertices are
+    /// bounded with [FitMethod::Precise], and all other meshes with [FitMethod::Fast].
+    pub fn with_fit_budget(fit_budget: usize) -> Self {
+        BoundingVolumePlugin {
+            fit_budget: Some(fit_budget),
+            early_sync: false,
+            #[cfg(feature = "debug")]
+            debug: false,
+            marker: PhantomData,
+        }
+    }
+
+    /// Marks every entity with a bounding volume of type T with [DebugBounds](debug::DebugBounds)
+    /// once its volume is computed, to show all bounding volumes of this type without marking each
+    /// entity.
+    #[cfg(feature = "debug")]
+    pub fn with_debug(mut self, debug: bool) -> Self {
+        self.debug = debug;
+        self
+    }
+
+    /// Additionally propagates transforms and updates bounding volumes at the start of
+    /// [CoreStage::Update], labeled [BoundingSystem::SyncBounds], so systems in that stage can read
+    /// bounds that match this frame's transforms instead of last frame's. The usual update in
+    /// [CoreStage::PostUpdate] still runs, to catch changes made during [CoreStage::Update].
+    pub fn with_early_sync(mut self) -> Self {
+        self.early_sync = true;
+        self
+    }
+}
+
+/// Marks that the early transform propagation has been added to the app, so the plugins of
+/// several bounding volume types only add it once.
+struct EarlyTransformSync;
+
+/// Marks that the systems shared by the debug meshes of all bounding volume types have been added
+/// to the app, so they are only added once.
+#[cfg(feature = "debug")]
+struct SharedDebugSystems;
+
 /// A plugin that provides functionality for generating and updating bounding volumes for meshes.
+///
+/// The plugin does not require the renderer. In a headless app (e.g. `MinimalPlugins`), only the
+/// bounding volume systems are added; these need an `Assets<Mesh>` resource to read meshes from.
+/// The debug systems only run while the PBR renderer is present, and a warning is logged at
+/// startup when it is missing, so headless apps should disable the `debug` feature. Add this plugin
+/// after the renderer, such as after `DefaultPlugins`, so the visibility of debug meshes is synced
+/// before the renderer collects the visible entities.
 impl<T> Plugin for BoundingVolumePlugin<T>
 where
     T: 'static + Send + Sync + BoundingVolume + Clone + Debug + Component,
     Mesh: From<&'static T>,
 {
     fn build(&self, app: &mut App) {
-        app.add_system_to_stage(CoreStage::PreUpdate, spawn::<T>.system())
+        // The vertex caches and decoder are shared by all bounding volume types, so they are only
+        // added once.
+        if !app.world.contains_resource::<MeshVertexCache>() {
+            app.init_resource::<MeshVertexCache>()
+                .init_resource::<SharedVertexCache>()
+                .init_resource::<PositionDecoder>()
+                .add_system_to_stage(CoreStage::Update, clear_vertex_cache.system())
+                .add_system_to_stage(
+                    CoreStage::PreUpdate,
+                    invalidate_shared_vertices
+                        .system()
+                        .before(BoundingSystem::Spawn),
+                );
+        }
+        app.init_resource::<SharedBoundsCache<T>>()
+            .init_resource::<DerivedBoundsClaims<T>>()
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                invalidate_shared_bounds::<T>
+                    .system()
+                    .before(BoundingSystem::Spawn),
+            )
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                spawn::<T>.system().label(BoundingSystem::Spawn),
+            )
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                spawn_multi_mesh::<T>.system().label(BoundingSystem::Spawn),
+            )
             .add_system_to_stage(
                 CoreStage::PostUpdate,
                 update::<T>
@@ -36,19 +149,130 @@ where
                     .label(BoundingSystem::UpdateBounds),
             )
             .add_system_to_stage(
+                CoreStage::PostUpdate,
+                update_multi_mesh::<T>
+                    .system()
+                    .after(TransformSystem::TransformPropagate)
+                    .label(BoundingSystem::UpdateBounds),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                bake_world_space_bounds::<T>
+                    .system()
+                    .after(BoundingSystem::UpdateBounds),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                bump_bounds_versions::<T>
+                    .system()
+                    .after(BoundingSystem::UpdateBounds),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                compute_world_aabbs::<T>
+                    .system()
+                    .after(BoundingSystem::UpdateBounds),
+            )
+            .insert_resource(FitBudget::<T>::new(self.fit_budget))
+            .init_resource::<ZeroScalePolicy>()
+            .init_resource::<SceneBounds<T>>()
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                update_scene_bounds::<T>
+                    .system()
+                    .after(BoundingSystem::UpdateBounds),
+            );
+        // Only types that can be derived from a box are worth a pass over the entities bounded
+        // with both.
+        if T::DERIVES_FROM_AABB {
+            app.add_system_to_stage(
+                CoreStage::PreUpdate,
+                spawn_from_aabbs::<T>.system().before(BoundingSystem::Spawn),
+            );
+        }
+        if self.early_sync {
+            if !app.world.contains_resource::<EarlyTransformSync>() {
+                app.insert_resource(EarlyTransformSync).add_system_to_stage(
+                    CoreStage::Update,
+                    bevy::transform::transform_propagate_system::transform_propagate_system
+                        .system()
+                        .label(TransformSystem::TransformPropagate),
+                );
+            }
+            app.add_system_to_stage(
+                CoreStage::Update,
+                update::<T>
+                    .system()
+                    .after(TransformSystem::TransformPropagate)
+                    .label(BoundingSystem::SyncBounds),
+            )
+            .add_system_to_stage(
+                CoreStage::Update,
+                update_multi_mesh::<T>
+                    .system()
+                    .after(TransformSystem::TransformPropagate)
+                    .label(BoundingSystem::SyncBounds),
+            );
+        }
+        // The debug systems only run while the render stack is present, which can only be known
+        // once every plugin has been added.
+        #[cfg(feature = "debug")]
+        {
+            if !app.world.contains_resource::<SharedDebugSystems>() {
+                app.insert_resource(SharedDebugSystems)
+                    .init_resource::<debug::DebugBoundsConfig>()
+                    .add_startup_system(warn_missing_debug_renderer.system())
+                    .add_system_to_stage(
+                        CoreStage::PostUpdate,
+                        remove_stale_debug_meshes
+                            .system()
+                            .with_run_criteria(debug_renderer_present.system()),
+                    )
+                    .add_system_to_stage(
+                        CoreStage::PostUpdate,
+                        update_debug_appearance
+                            .system()
+                            .with_run_criteria(debug_renderer_present.system()),
+                    );
+            }
+            app.add_system_to_stage(
                 CoreStage::PostUpdate,
                 update_debug_meshes::<T>
                     .system()
+                    .with_run_criteria(debug_renderer_present.system())
                     .after(BoundingSystem::UpdateBounds)
                     .label(BoundingSystem::UpdateDebug),
             )
             .add_system_to_stage(
                 CoreStage::PostUpdate,
-                update_debug_mesh_visibility::<T>
+                remove_stale_debug_snapshots::<T>
                     .system()
-                    .after(BoundingSystem::UpdateDebug)
-                    .before(bevy::render::RenderSystem::VisibleEntities),
+                    .with_run_criteria(debug_renderer_present.system()),
             );
+            let update_visibility = update_debug_mesh_visibility::<T>
+                .system()
+                .with_run_criteria(debug_renderer_present.system())
+                .after(BoundingSystem::UpdateDebug);
+            // The renderer's visibility systems only exist if its plugins were added first.
+            if app.world.contains_resource::<Assets<StandardMaterial>>() {
+                app.add_system_to_stage(
+                    CoreStage::PostUpdate,
+                    update_visibility.before(bevy::render::RenderSystem::VisibleEntities),
+                );
+            } else {
+                app.add_system_to_stage(CoreStage::PostUpdate, update_visibility);
+            }
+            if self.debug {
+                app.add_system_to_stage(
+                    CoreStage::PostUpdate,
+                    debug_all_bounds::<T>
+                        .system()
+                        .with_run_criteria(debug_renderer_present.system())
+                        .before(BoundingSystem::UpdateDebug),
+                );
+            }
+            T::register_debug(app);
+        }
     }
 }
 
@@ -66,82 +290,1672 @@ impl<T: BoundingVolume + Send + Sync> Default for Bounded<T> {
     }
 }
 
+/// Marks an instance of a mesh whose bounding volume can be shared with every other instance of the
+/// same mesh. The bounding volume is only computed once for all marked entities with the same mesh
+/// handle, instead of once per entity, even when they are spawned in different frames. Only
+/// bounding volumes that depend on the transform, such as [Aabb], are then updated for each
+/// instance, from vertices that are also only read once.
+///
+/// ## Note
+/// The shared bounding volumes and vertices, see [SharedBoundsCache] and [SharedVertexCache], are
+/// kept in memory until the mesh is modified or removed.
+#[derive(Debug, Clone, Copy, Default, Component)]
+pub struct SharedBounds;
+
+/// The bounding volumes of type T shared by the entities marked with [SharedBounds], keyed by the
+/// mesh handle, attribute, and range they were fit to. Entries are kept across frames, and are
+/// removed by [invalidate_shared_bounds] when their mesh is modified or removed.
+pub struct SharedBoundsCache<T: BoundingVolume + Send + Sync> {
+    bounds: HashMap<VertexSourceKey, (T, Option<FitMethod>, BoundsPose<T>)>,
+}
+
+impl<T: BoundingVolume + Send + Sync> Default for SharedBoundsCache<T> {
+    fn default() -> Self {
+        SharedBoundsCache {
+            bounds: HashMap::default(),
+        }
+    }
+}
+
+/// Removes the bounding volumes of modified or removed meshes from the [SharedBoundsCache].
+pub fn invalidate_shared_bounds<T: 'static + BoundingVolume + Send + Sync>(
+    mut events: EventReader<AssetEvent<Mesh>>,
+    mut cache: ResMut<SharedBoundsCache<T>>,
+) {
+    let stale = stale_meshes(&mut events);
+    if !stale.is_empty() {
+        cache.bounds.retain(|key, _| !stale.contains(&key.0));
+    }
+}
+
+/// Fits the bounding volumes of an entity with this transform in place of its [GlobalTransform],
+/// such as to bound an animated character at a canonical T-pose rather than whatever pose is
+/// active when its mesh loads. Changes to the [GlobalTransform] of the entity then no longer
+/// refit its bounding volumes; changes to this component do.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Component)]
+pub struct BoundsReferenceTransform(pub GlobalTransform);
+
+impl BoundsReferenceTransform {
+    /// Returns the transform that an entity is bounded with: the reference transform if there is
+    /// one, or else the entity's own `transform`.
+    pub(crate) fn resolve<'a>(
+        reference: Option<&'a BoundsReferenceTransform>,
+        transform: &'a GlobalTransform,
+    ) -> &'a GlobalTransform {
+        reference.map_or(transform, |reference| &reference.0)
+    }
+}
+
+/// Marks an entity whose bounding volumes are supplied by the user, such as bounds authored in a
+/// tool, and inserted directly with the constructors of each type. The mesh of a marked entity is
+/// never read: it isn't bounded by the `spawn` systems, even if it is marked with [Bounded], and
+/// its bounding volumes are never recomputed when its mesh or [GlobalTransform] changes.
+#[derive(Debug, Clone, Copy, Default, Component)]
+pub struct ManualBounds;
+
+/// Marks an entity that never moves, so the [WorldSpaceBounds] of its bounding volumes are baked
+/// once they are computed.
+#[derive(Debug, Clone, Copy, Default, Component)]
+pub struct Static;
+
+/// The bounding volume of type T of a [Static] entity, baked in world space whenever the bounding
+/// volume is computed. Bounding volumes are stored in mesh space, so every query needs the
+/// [GlobalTransform] of the entity; querying this instead skips the transform step for scenery
+/// that never moves. It should be used with an identity [GlobalTransform].
+#[derive(Debug, Clone, Component)]
+pub struct WorldSpaceBounds<T: BoundingVolume + Send + Sync>(pub T);
+
+/// Bakes the [WorldSpaceBounds] of [Static] entities whose bounding volumes have been computed.
+#[allow(clippy::type_complexity)]
+pub fn bake_world_space_bounds<T: 'static + BoundingVolume + Send + Sync + Component>(
+    mut commands: Commands,
+    query: Query<(Entity, &T, &GlobalTransform), (With<Static>, Or<(Changed<T>, Added<Static>)>)>,
+) {
+    for (entity, bound_vol, transform) in query.iter() {
+        commands
+            .entity(entity)
+            .insert(WorldSpaceBounds(bound_vol.to_world_space(transform)));
+    }
+}
+
+/// A counter that is incremented whenever any bounding volume of the entity is written, starting at
+/// 1 when the first one is computed. Consumers outside of the schedule of this plugin, such as a
+/// spatial index or network replication, can store the last version they have seen and compare it
+/// cheaply, which doesn't depend on when they run like `Changed<T>` does.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Component)]
+pub struct BoundsVersion(pub u64);
+
+/// Increments the [BoundsVersion] of entities whose bounding volume of type T has been written
+/// this frame, and adds it to entities that don't have one yet.
+pub fn bump_bounds_versions<T: 'static + BoundingVolume + Send + Sync + Component>(
+    mut commands: Commands,
+    mut query: Query<(Entity, Option<&mut BoundsVersion>), Changed<T>>,
+) {
+    for (entity, version) in query.iter_mut() {
+        match version {
+            Some(mut version) => version.0 += 1,
+            None => {
+                commands.entity(entity).insert(BoundsVersion(1));
+            }
+        }
+    }
+}
+
+/// Marks an entity to have its [WorldAabb] computed from its bounding volume, see
+/// [compute_world_aabbs].
+#[derive(Debug, Clone, Copy, Default, Component)]
+pub struct ComputeWorldAabb;
+
+/// The world space axis-aligned bounding box of an entity marked with [ComputeWorldAabb], as
+/// returned by [BoundingVolume::world_aabb], so it can be queried, filtered, and sorted on without
+/// the [GlobalTransform] of the entity. It is only written when the bounding volume or the
+/// transform has changed, so it works with change detection. If the entity has more than one type
+/// of bounding volume, the box is computed from whichever was updated last.
+#[derive(Debug, Clone, Component)]
+pub struct WorldAabb(pub Aabb);
+
+/// Writes the [WorldAabb] of entities marked with [ComputeWorldAabb] whose bounding volume of type
+/// T, or transform, has changed.
+#[allow(clippy::type_complexity)]
+pub fn compute_world_aabbs<T: 'static + BoundingVolume + Send + Sync + Component>(
+    mut commands: Commands,
+    query: Query<
+        (Entity, &T, &GlobalTransform),
+        (
+            With<ComputeWorldAabb>,
+            Or<(
+                Changed<T>,
+                Changed<GlobalTransform>,
+                Added<ComputeWorldAabb>,
+            )>,
+        ),
+    >,
+) {
+    for (entity, bound_vol, transform) in query.iter() {
+        commands
+            .entity(entity)
+            .insert(WorldAabb(bound_vol.world_aabb(transform)));
+    }
+}
+
 /// A [BoundingVolume] stores its properties in mesh space to maximize precision. Because some types
 /// of bounding volume must be recomputed if the mesh is scaled or rotated, this trait calls an
 /// update function depending on whether the mesh or transform has updated.
 pub trait BoundingVolume {
-    /// Initializes a valid bounding volume given a [Mesh] and [GlobalTransform].
-    fn new(mesh: &Mesh, transform: &GlobalTransform) -> Self;
-    /// Generate a debug [Mesh] representing the bounding volume from a [BoundingVolume].
-    fn new_debug_mesh(&self, transform: &GlobalTransform) -> Mesh;
-    /// This function is only called when only the entity's [GlobalTransform] has changed. Only
-    /// some types of bounding volume need to be recomputed in this case.
+    /// Initializes a valid bounding volume given a [Mesh] and [GlobalTransform]. Fails if the
+    /// vertex positions of the mesh can't be read. Positions in a custom format are read by passing
+    /// a [VertexSource] with a [PositionDecoder] instead of the mesh.
+    fn new<'a>(
+        source: impl Into<VertexSource<'a>>,
+        transform: &GlobalTransform,
+    ) -> Result<Self, BoundingError>
+    where
+        Self: Sized,
+    {
+        Ok(Self::from_vertices(&source.into().vertices()?, transform))
+    }
+    /// Initializes a bounding volume over the bulk of the mesh, ignoring thin protruding details
+    /// such as antennae or flagpoles, see [bulk_vertices](vertex::bulk_vertices).
+    ///
+    /// ## Note
+    /// The result is no longer a strict bound: the discarded details lie outside of the volume. It
+    /// should only be used where a volume that mostly contains the mesh is acceptable, such as for
+    /// visual culling.
+    fn new_bulk<'a>(
+        source: impl Into<VertexSource<'a>>,
+        transform: &GlobalTransform,
+        min_area_ratio: f32,
+    ) -> Result<Self, BoundingError>
+    where
+        Self: Sized,
+    {
+        Ok(Self::from_vertices(
+            &bulk_vertices(source, min_area_ratio)?,
+            transform,
+        ))
+    }
+    /// Initializes a valid bounding volume given the mesh space vertex positions of a mesh and its
+    /// [GlobalTransform].
+    fn from_vertices(vertices: &[Vec3], transform: &GlobalTransform) -> Self
+    where
+        Self: Sized;
+    /// Initializes a bounding volume like [BoundingVolume::from_vertices], trading fit quality for
+    /// speed as selected by the [FitMethod]. Types with a single fitting method ignore the method.
+    fn from_vertices_with_method(
+        vertices: &[Vec3],
+        transform: &GlobalTransform,
+        _method: FitMethod,
+    ) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_vertices(vertices, transform)
+    }
+    /// Whether the bounding volume can be derived from an [Aabb] with
+    /// [BoundingVolume::derive_from_aabb]. The [spawn_from_aabbs] system is only added for types
+    /// that can.
+    const DERIVES_FROM_AABB: bool = false;
+    /// Derives the bounding volume from an [Aabb] fit with the same `transform`, instead of fitting
+    /// it to the vertices of the mesh, see [spawn_from_aabbs]. Returns `None`, the default, for
+    /// types that can't be derived from a box.
+    fn derive_from_aabb(_aabb: &Aabb, _transform: &GlobalTransform) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+    /// Adds the debug systems that only apply to this type of bounding volume, such as the axes of
+    /// an [obb::Obb], next to the debug meshes shared by every type. Does nothing by default.
+    #[cfg(feature = "debug")]
+    fn register_debug(_app: &mut App)
+    where
+        Self: Sized,
+    {
+    }
+    /// Generate a debug [Mesh] representing the bounding volume from a [BoundingVolume], as
+    /// configured by the [DebugMeshOptions].
+    fn new_debug_mesh(
+        &self,
+        transform: &GlobalTransform,
+        options: &DebugMeshOptions,
+    ) -> Result<Mesh, BoundingError>;
+    /// This function is only called when only the rotation or scale of the entity's
+    /// [GlobalTransform] has changed since its [BoundsPose]; volumes are stored in mesh space, so
+    /// pure translations never change them. Only some types of bounding volume need to be
+    /// recomputed in this case, and only these should read the vertices from the [VertexSource].
+    ///
+    /// `None` means the bounding volume is unchanged, and leaves the component untouched. A volume
+    /// that is returned is only written if it differs from the stored one, see [UPDATE_EPSILON], so
+    /// `Changed<T>` is only triggered when the volume actually changed.
     fn update_on_transform_change(
         &self,
-        _mesh: &Mesh,
+        _vertices: &VertexSource,
         _transform: &GlobalTransform,
     ) -> Option<Self>
     where
         Self: Sized;
     /// Returns true iff the bounding mesh is entirely on the outside of the supplied plane.
-    /// "Outside" is the direction that the plane normal points to.
+    /// "Outside" is the direction that the plane normal points to. The normal doesn't need to be
+    /// unit length.
     fn outside_plane(
         &self,
         bound_vol_position: &GlobalTransform,
         point: Vec3,
         normal: Vec3,
     ) -> bool;
+    /// Returns the axis-aligned bounding box of this bounding volume in world space. Because the
+    /// returned [Aabb] is already in world space, it should be used with an identity
+    /// [GlobalTransform].
+    ///
+    /// This is conservative for every type: a sphere returns its center +/- its radius, an [Aabb]
+    /// is already rotated and scaled so it only needs to be translated, and an [obb::Obb] refits
+    /// a box to its transformed corners. This is the method to use to feed any type of bounding
+    /// volume into a broad phase, spatial hash, or BVH.
+    fn world_aabb(&self, bound_vol_position: &GlobalTransform) -> Aabb;
+    /// Returns the world space rectangle covering the projection of the bounding volume onto the
+    /// XZ ground plane, such as the footprint of an entity on a top-down minimap. The `x` and `y`
+    /// of the rectangle are the world `x` and `z` coordinates.
+    fn footprint_xz(&self, bound_vol_position: &GlobalTransform) -> Rect2d {
+        let aabb = self.world_aabb(bound_vol_position);
+        Rect2d {
+            min: Vec2::new(aabb.minimums().x, aabb.minimums().z),
+            max: Vec2::new(aabb.maximums().x, aabb.maximums().z),
+        }
+    }
+    /// Returns the minimum and maximum of the projection of the bounding volume in world space onto
+    /// `axis`, as the scalar `dot(point, axis)`. If `axis` isn't normalized, the projection is
+    /// scaled by its length. This is the building block of separating axis tests and
+    /// sweep-and-prune.
+    fn project_onto_axis(&self, bound_vol_position: &GlobalTransform, axis: Vec3) -> (f32, f32);
+    /// Returns true iff this bounding volume and `other` are equal within `epsilon`. Exact float
+    /// equality is rarely useful after recomputing a bounding volume, so this should be used to
+    /// compare bounding volumes instead.
+    fn approx_eq(&self, other: &Self, epsilon: f32) -> bool
+    where
+        Self: Sized;
+    /// Returns a copy of this bounding volume scaled by `factor` about its own center, in mesh
+    /// space, so it stays symmetric around the bounded mesh.
+    fn inflated(&self, factor: f32) -> Self
+    where
+        Self: Sized;
+    /// Returns true iff this bounding volume and `other`, a bounding volume of the same type,
+    /// overlap in world space. This lets generic code over any [BoundingVolume], such as a system
+    /// that finds all overlapping pairs, use the intersection test specific to each type.
+    fn intersects(
+        &self,
+        bound_vol_position: &GlobalTransform,
+        other: &Self,
+        other_position: &GlobalTransform,
+    ) -> bool
+    where
+        Self: Sized;
+    /// Returns this bounding volume with the transform baked in, so it is in world space and should
+    /// be used with an identity [GlobalTransform]. See [WorldSpaceBounds].
+    fn to_world_space(&self, bound_vol_position: &GlobalTransform) -> Self
+    where
+        Self: Sized;
+    /// Returns the geometric center of the bounding volume in world space, so generic code can
+    /// treat any bounding volume as a point, such as to sort entities by distance.
+    fn center(&self, bound_vol_position: &GlobalTransform) -> Vec3;
+    /// Returns true iff the world space `point` is inside of, or on the surface of, the bounding
+    /// volume.
+    fn contains_point(&self, bound_vol_position: &GlobalTransform, point: Vec3) -> bool;
+    /// Returns the volume enclosed by the bounding volume in world space.
+    fn world_volume(&self, bound_vol_position: &GlobalTransform) -> f32;
+    /// Returns the unit direction in world space along which the bounding volume is longest, and
+    /// its full length along it, such as to choose a split axis or to orient a label. For a sphere,
+    /// every direction is equally long, so the x-axis is returned with the diameter.
+    fn longest_axis(&self, bound_vol_position: &GlobalTransform) -> (Vec3, f32);
+    /// Returns the kind of this bounding volume, so generic code can select the right algorithm
+    /// for a volume, or a pair of volumes, at runtime.
+    fn kind(&self) -> BoundingKind;
+    /// Returns true iff the bounding volume overlaps the slab between two parallel planes, which
+    /// contains the points `p` where `d_near <= normal.dot(p) <= d_far`. This is useful to cull
+    /// against a depth interval, such as a shadow map cascade. The distances are in units of the
+    /// length of `normal`, so it should usually be unit length.
+    fn intersects_slab(
+        &self,
+        bound_vol_position: &GlobalTransform,
+        normal: Vec3,
+        d_near: f32,
+        d_far: f32,
+    ) -> bool {
+        let (minimum, maximum) = self.project_onto_axis(bound_vol_position, normal);
+        maximum >= d_near && minimum <= d_far
+    }
+    /// Returns the radius, in pixels, of the bounding volume projected onto the screen of a
+    /// perspective camera, which is the standard metric for selecting a level of detail. `fov_y`
+    /// is the vertical field of view of the camera in radians. Boxes are approximated by the
+    /// sphere around the diagonal of their [BoundingVolume::world_aabb]. If the camera is inside of
+    /// this sphere, `f32::INFINITY` is returned.
+    fn projected_size(
+        &self,
+        bound_vol_position: &GlobalTransform,
+        camera: &GlobalTransform,
+        fov_y: f32,
+        viewport_height: f32,
+    ) -> f32 {
+        let aabb = self.world_aabb(bound_vol_position);
+        let center = (aabb.minimums() + aabb.maximums()) * 0.5;
+        let radius = (aabb.maximums() - aabb.minimums()).length() * 0.5;
+        projected_radius(center, radius, camera, fov_y, viewport_height)
+    }
+    /// Classifies the bounding volume against a set of `(point, normal)` planes, such as the
+    /// planes of a view frustum, with normals pointing outward. If the volume is entirely outside
+    /// of any plane, the index of the first such plane is reported, to help debug culling.
+    fn classify_against_planes(
+        &self,
+        bound_vol_position: &GlobalTransform,
+        planes: &[(Vec3, Vec3)],
+    ) -> CullResult {
+        let mut result = CullResult::Visible;
+        for (plane_index, (point, normal)) in planes.iter().enumerate() {
+            if self.outside_plane(bound_vol_position, *point, *normal) {
+                return CullResult::Culled { plane_index };
+            }
+            // If the volume isn't entirely on the inside of the plane, it straddles it.
+            if !self.outside_plane(bound_vol_position, *point, -*normal) {
+                result = CullResult::Intersecting;
+            }
+        }
+        result
+    }
+}
+
+/// Returns the entities whose bounding volumes contain the world space `point`, sorted by the
+/// volume of their bounding volumes, smallest first, so the innermost entity comes first. This is
+/// the selection primitive for picking by point, and is meant to be called with the iterator of a
+/// query, e.g. `entities_containing_point(point, query.iter())` with a
+/// `Query<(Entity, &Aabb, &GlobalTransform)>`.
+pub fn entities_containing_point<'a, T: 'a + BoundingVolume>(
+    point: Vec3,
+    bound_vols: impl IntoIterator<Item = (Entity, &'a T, &'a GlobalTransform)>,
+) -> Vec<Entity> {
+    let mut containing: Vec<(Entity, f32)> = bound_vols
+        .into_iter()
+        .filter(|(_, bound_vol, transform)| bound_vol.contains_point(transform, point))
+        .map(|(entity, bound_vol, transform)| (entity, bound_vol.world_volume(transform)))
+        .collect();
+    containing.sort_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    containing.into_iter().map(|(entity, _)| entity).collect()
+}
+
+/// The order of the entities returned by [sort_by_camera_distance].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DepthOrder {
+    /// Nearest first, such as to draw opaque meshes or to select levels of detail.
+    FrontToBack,
+    /// Furthest first, such as to draw transparent meshes.
+    BackToFront,
+}
+
+/// What the distances computed by [sort_by_camera_distance] are measured to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DistanceTo {
+    /// The center of the bounding volume, see [BoundingVolume::center].
+    Center,
+    /// The nearest surface of the sphere around the diagonal of the
+    /// [BoundingVolume::world_aabb], which is the distance to the center minus the radius of that
+    /// sphere, or 0 if the camera is inside of it.
+    NearestSurface,
+}
+
+/// Returns the entities with their distances from the world space `camera_position`, measured as
+/// selected by `distance_to`, and sorted in the given `order`. This is meant to be called with the
+/// iterator of a query, like [entities_containing_point].
+pub fn sort_by_camera_distance<'a, T: 'a + BoundingVolume>(
+    camera_position: Vec3,
+    order: DepthOrder,
+    distance_to: DistanceTo,
+    bound_vols: impl IntoIterator<Item = (Entity, &'a T, &'a GlobalTransform)>,
+) -> Vec<(Entity, f32)> {
+    let mut distances: Vec<(Entity, f32)> = bound_vols
+        .into_iter()
+        .map(|(entity, bound_vol, transform)| {
+            let center_distance = bound_vol.center(transform).distance(camera_position);
+            let distance = match distance_to {
+                DistanceTo::Center => center_distance,
+                DistanceTo::NearestSurface => {
+                    let aabb = bound_vol.world_aabb(transform);
+                    let radius = (aabb.maximums() - aabb.minimums()).length() * 0.5;
+                    (center_distance - radius).max(0.0)
+                }
+            };
+            (entity, distance)
+        })
+        .collect();
+    distances.sort_by(|(_, a), (_, b)| {
+        let ordering = a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal);
+        match order {
+            DepthOrder::FrontToBack => ordering,
+            DepthOrder::BackToFront => ordering.reverse(),
+        }
+    });
+    distances
+}
+
+/// Projects the radius of a sphere in world space onto the screen of a perspective camera, in
+/// pixels. See [BoundingVolume::projected_size].
+pub(crate) fn projected_radius(
+    center: Vec3,
+    radius: f32,
+    camera: &GlobalTransform,
+    fov_y: f32,
+    viewport_height: f32,
+) -> f32 {
+    let distance = center.distance(camera.translation);
+    if distance <= radius {
+        return f32::INFINITY;
+    }
+    let focal_length = viewport_height / (2.0 * (fov_y / 2.0).tan());
+    radius / distance * focal_length
+}
+
+/// An axis-aligned rectangle, see [BoundingVolume::footprint_xz].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Rect2d {
+    pub min: Vec2,
+    pub max: Vec2,
+}
+
+/// How precisely a bounding volume is fit to the vertices of a mesh, see [FitBudget].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FitMethod {
+    /// The slower method that gives the tightest volume.
+    Precise,
+    /// The faster, approximate method, for meshes with many vertices.
+    Fast,
+}
+
+/// The number of vertices below which bounding volumes of type T are fit with
+/// [FitMethod::Precise], and at or above which they are fit with [FitMethod::Fast]. Without a
+/// budget, [BoundingVolume::from_vertices] is used. This resource is added by the
+/// [BoundingVolumePlugin], and can be changed at runtime.
+#[derive(Debug)]
+pub struct FitBudget<T: BoundingVolume> {
+    pub vertices: Option<usize>,
+    marker: PhantomData<T>,
+}
+
+impl<T: BoundingVolume> FitBudget<T> {
+    pub fn new(vertices: Option<usize>) -> Self {
+        FitBudget {
+            vertices,
+            marker: PhantomData,
+        }
+    }
+    /// Returns the method to use for the given number of vertices, if there is a budget.
+    pub fn method(&self, vertex_count: usize) -> Option<FitMethod> {
+        self.vertices.map(|budget| {
+            if vertex_count < budget {
+                FitMethod::Precise
+            } else {
+                FitMethod::Fast
+            }
+        })
+    }
+    /// Fits a bounding volume to the vertices with the method selected by this budget, which is
+    /// returned alongside it, or with [BoundingVolume::from_vertices] if there is no budget.
+    pub fn fit(&self, vertices: &[Vec3], transform: &GlobalTransform) -> (T, Option<FitMethod>) {
+        match self.method(vertices.len()) {
+            Some(method) => (
+                T::from_vertices_with_method(vertices, transform, method),
+                Some(method),
+            ),
+            None => (T::from_vertices(vertices, transform), None),
+        }
+    }
+}
+
+/// The [FitMethod] that was used for the bounding volume of type T on this entity. This is only
+/// added to entities that are bounded while a [FitBudget] is set, and is updated whenever their
+/// volume is refit from their mesh.
+#[derive(Debug, Clone, Component)]
+pub struct ChosenFitMethod<T: BoundingVolume + Send + Sync> {
+    pub method: FitMethod,
+    marker: PhantomData<T>,
+}
+
+impl<T: BoundingVolume + Send + Sync> ChosenFitMethod<T> {
+    pub fn new(method: FitMethod) -> Self {
+        ChosenFitMethod {
+            method,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// The rotation and scale of the [GlobalTransform] that the bounding volume of type T on this
+/// entity was last fit or updated for. Volumes are stored in mesh space, so only a change of the
+/// rotation or scale can change them, and they aren't updated again until one of these changes.
+/// The pose is kept apart from the volume, so recording it doesn't trigger `Changed<T>`.
+#[derive(Debug, Clone, Component)]
+pub struct BoundsPose<T: BoundingVolume + Send + Sync> {
+    pub rotation: Quat,
+    pub scale: Vec3,
+    marker: PhantomData<T>,
+}
+
+impl<T: BoundingVolume + Send + Sync> BoundsPose<T> {
+    pub fn new(transform: &GlobalTransform) -> Self {
+        BoundsPose {
+            rotation: transform.rotation,
+            scale: transform.scale,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns true iff `transform` has the rotation and scale of this pose.
+    pub fn matches(&self, transform: &GlobalTransform) -> bool {
+        self.rotation == transform.rotation && self.scale == transform.scale
+    }
+}
+
+/// Options for generating the debug [Mesh] of a [BoundingVolume]. When added to an entity with
+/// `DebugBounds`, these options are used for its debug mesh.
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct DebugMeshOptions {
+    /// The number of line segments used to draw each circle of a sphere. Solid spheres are
+    /// subdivided to roughly match. Boxes ignore this.
+    pub resolution: usize,
+    /// Whether to generate a wireframe or a solid mesh.
+    pub topology: DebugMeshTopology,
+    /// The factor the bounding volume is scaled by about its center, see
+    /// [BoundingVolume::inflated].
+    pub inflation: f32,
+}
+
+impl Default for DebugMeshOptions {
+    fn default() -> Self {
+        DebugMeshOptions {
+            resolution: 24,
+            topology: DebugMeshTopology::Lines,
+            inflation: 1.0,
+        }
+    }
+}
+
+/// The topology of a debug [Mesh], see [DebugMeshOptions].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DebugMeshTopology {
+    /// A wireframe made of lines.
+    Lines,
+    /// A solid volume made of triangles.
+    Triangles,
+}
+
+/// The kinds of [BoundingVolume] provided by this crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoundingKind {
+    Aabb,
+    Obb,
+    Sphere,
+}
+
+/// The result of classifying a [BoundingVolume] against a set of planes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CullResult {
+    /// The volume is entirely inside all of the planes.
+    Visible,
+    /// The volume is entirely outside of the plane at `plane_index`.
+    Culled { plane_index: usize },
+    /// The volume is not outside of any plane, but straddles at least one of them.
+    Intersecting,
+}
+
+/// The ways that reading the vertices of a [Mesh] to compute a bounding volume can fail, such as
+/// for meshes loaded from untrusted assets. The ECS systems log these errors and skip the entity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BoundingError {
+    /// The mesh doesn't contain the attribute the vertices are read from.
+    MissingAttribute(Cow<'static, str>),
+    /// The attribute the vertices are read from doesn't contain `Float32x3` positions.
+    UnexpectedAttributeType(Cow<'static, str>),
+    /// The [BoundsVertexRange] selects vertices past the end of the attribute.
+    VertexRangeOutOfBounds {
+        range: Range<usize>,
+        vertex_count: usize,
+    },
+    /// The entity has no [GlobalTransform], or no `Handle<Mesh>` of a loaded mesh.
+    MeshNotFound(Entity),
+    /// The `ancestor` entity is not an ancestor of `entity` in the hierarchy.
+    NotAnAncestor { entity: Entity, ancestor: Entity },
+    /// A binary blob read with `from_bytes` doesn't have the length of the bounding volume.
+    InvalidByteLength { expected: usize, found: usize },
+    /// A parameter of a bounding volume constructor is outside of its valid range.
+    InvalidParameter(String),
+    /// A resource needed to compute bounding volumes, with the given type name, is missing from
+    /// the world.
+    MissingResource(&'static str),
+}
+
+impl std::fmt::Display for BoundingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BoundingError::MissingAttribute(attribute) => {
+                write!(f, "Mesh does not contain the {} attribute", attribute)
+            }
+            BoundingError::UnexpectedAttributeType(attribute) => {
+                write!(f, "Unexpected vertex types in {}", attribute)
+            }
+            BoundingError::VertexRangeOutOfBounds {
+                range,
+                vertex_count,
+            } => write!(
+                f,
+                "Vertex range {:?} is out of bounds of the {} vertices",
+                range, vertex_count
+            ),
+            BoundingError::MeshNotFound(entity) => {
+                write!(f, "Entity {:?} has no transform or loaded mesh", entity)
+            }
+            BoundingError::NotAnAncestor { entity, ancestor } => {
+                write!(f, "{:?} is not an ancestor of {:?}", ancestor, entity)
+            }
+            BoundingError::InvalidByteLength { expected, found } => write!(
+                f,
+                "Expected {} bytes of bounding volume data, found {}",
+                expected, found
+            ),
+            BoundingError::InvalidParameter(message) => write!(f, "Invalid parameter: {}", message),
+            BoundingError::MissingResource(resource) => {
+                write!(f, "The {} resource is missing", resource)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BoundingError {}
+
+/// Any component of the scale of a [GlobalTransform] with a smaller magnitude than this is
+/// considered zero by the [ZeroScalePolicy].
+pub const MIN_SCALE: f32 = 1e-6;
+
+/// How the bounding volume systems handle a [GlobalTransform] with a zero, or near zero, scale on
+/// any axis, such as at the start of a spawn-in animation. Fitting to a collapsed transform
+/// collapses the bounding volume, and any later division by the scale produces NaNs. This resource
+/// is added by the [BoundingVolumePlugin], defaults to [ZeroScalePolicy::Skip], and can be changed
+/// at runtime.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ZeroScalePolicy {
+    /// Skips updates while the scale is collapsed, keeping the last valid bounding volume. New
+    /// bounding volumes are only computed once the scale is valid.
+    Skip,
+    /// Fits bounding volumes with the magnitude of each component of the scale clamped to at least
+    /// the given minimum, which is itself at least [MIN_SCALE].
+    Clamp(f32),
+}
+
+impl Default for ZeroScalePolicy {
+    fn default() -> Self {
+        ZeroScalePolicy::Skip
+    }
+}
+
+impl ZeroScalePolicy {
+    /// Returns the transform to fit bounding volumes with, or `None` if the fit should be skipped.
+    pub fn apply(&self, transform: &GlobalTransform) -> Option<GlobalTransform> {
+        if transform.scale.abs().min_element() >= MIN_SCALE {
+            return Some(*transform);
+        }
+        match *self {
+            ZeroScalePolicy::Skip => None,
+            ZeroScalePolicy::Clamp(minimum) => Some(GlobalTransform {
+                scale: transform.scale.signum()
+                    * transform
+                        .scale
+                        .abs()
+                        .max(Vec3::splat(minimum.max(MIN_SCALE))),
+                ..*transform
+            }),
+        }
+    }
 }
 
+/// The number of frames an entity marked with [Bounded] can wait for its mesh to load before a
+/// warning is logged.
+const UNLOADED_MESH_WARNING_FRAMES: usize = 300;
+
 /// Spawns a new [BoundingVolume], replacing the [AddBoundingVolume] marker component on the
 /// entity. This new BoundingVolume is fully initialized and will be kept up to date with the
-/// `update()` system.
+/// `update()` system. If the mesh of an entity hasn't loaded after 300 frames, a warning is
+/// logged, because the entity may never be bounded.
 #[allow(clippy::type_complexity)]
-pub fn spawn<T: 'static + BoundingVolume + Send + Sync + Debug + Component>(
+pub fn spawn<T: 'static + BoundingVolume + Clone + Send + Sync + Debug + Component>(
     mut commands: Commands,
     meshes: Res<Assets<Mesh>>,
-    query: Query<(&Handle<Mesh>, &GlobalTransform, Entity), With<Bounded<T>>>,
+    mut vertex_cache: ResMut<MeshVertexCache>,
+    mut shared_vertices: ResMut<SharedVertexCache>,
+    mut shared_bounds: ResMut<SharedBoundsCache<T>>,
+    decoder: Res<PositionDecoder>,
+    fit_budget: Res<FitBudget<T>>,
+    zero_scale_policy: Res<ZeroScalePolicy>,
+    mut derived_claims: ResMut<DerivedBoundsClaims<T>>,
+    mut unloaded_frames: Local<HashMap<Entity, usize>>,
+    query: Query<
+        (
+            &Handle<Mesh>,
+            &GlobalTransform,
+            Entity,
+            Option<&BoundsSourceAttribute>,
+            Option<&BoundsVertexRange>,
+            Option<&BoundsProxyPoints>,
+            Option<&SharedBounds>,
+            Option<&LockBoundsToHandle>,
+            Option<&BoundsReferenceTransform>,
+        ),
+        (
+            With<Bounded<T>>,
+            Without<ManualBounds>,
+            Without<BoundsMeshes>,
+        ),
+    >,
 ) {
-    for (handle, transform, entity) in query.iter() {
+    let mut still_unloaded = HashMap::default();
+    for (handle, transform, entity, attribute, range, proxy, shared, lock, reference) in
+        query.iter()
+    {
+        if derived_claims.entities.contains(&entity) {
+            continue;
+        }
+        let handle = LockBoundsToHandle::resolve(lock, handle);
+        let transform = BoundsReferenceTransform::resolve(reference, transform);
+        let transform = &match zero_scale_policy.apply(transform) {
+            Some(transform) => transform,
+            // The entity is bounded once its scale is valid again.
+            None => continue,
+        };
         if let Some(mesh) = meshes.get(handle) {
-            let new_bound = T::new(mesh, transform);
+            let source = VertexSource::with_attribute(mesh, attribute)
+                .with_range(range)
+                .with_proxy(proxy)
+                .with_decoder(&decoder);
+            // Proxy points belong to a single entity, so their bounds are never shared.
+            let shared_key = source.key(handle).filter(|_| shared.is_some());
+            let cached = shared_key
+                .as_ref()
+                .and_then(|key| shared_bounds.bounds.get(key))
+                .cloned();
+            let vertices = match shared_key {
+                Some(_) => shared_vertices.get_or_insert(handle, &source),
+                None => vertex_cache.get_or_insert(handle, &source),
+            };
+            let vertices = match vertices {
+                Ok(vertices) => vertices,
+                Err(err) => {
+                    // The mesh won't become readable by waiting, so the entity is no longer
+                    // tracked.
+                    error!("Could not bound entity {:?}: {}", entity, err);
+                    commands.entity(entity).remove::<Bounded<T>>();
+                    continue;
+                }
+            };
+            let (new_bound, method) = match cached {
+                // The shared bound was computed with the pose of another instance, so it is only
+                // updated for a different pose, and only if it depends on it.
+                Some((bound, method, pose)) if !pose.matches(transform) => {
+                    let cached_source = VertexSource::new(mesh).with_points(vertices);
+                    let bound = bound
+                        .update_on_transform_change(&cached_source, transform)
+                        .unwrap_or(bound);
+                    (bound, method)
+                }
+                Some((bound, method, _)) => (bound, method),
+                None => {
+                    let (bound, method) = fit_budget.fit(vertices, transform);
+                    if let Some(key) = shared_key {
+                        let pose = BoundsPose::new(transform);
+                        shared_bounds
+                            .bounds
+                            .insert(key, (bound.clone(), method, pose));
+                    }
+                    (bound, method)
+                }
+            };
             info!("New bounding volume generated: {:?}", new_bound);
-            commands
-                .entity(entity)
+            let mut entity_commands = commands.entity(entity);
+            entity_commands
                 .insert(new_bound)
+                .insert(BoundsPose::<T>::new(transform))
+                .remove::<Bounded<T>>();
+            if let Some(method) = method {
+                entity_commands.insert(ChosenFitMethod::<T>::new(method));
+            }
+        } else {
+            let frames = unloaded_frames.get(&entity).copied().unwrap_or(0) + 1;
+            if frames == UNLOADED_MESH_WARNING_FRAMES {
+                warn!(
+                    "Entity {:?} has waited {} frames for its mesh {:?} to load, and has no \
+                    bounding volume",
+                    entity, frames, handle
+                );
+            }
+            still_unloaded.insert(entity, frames);
+        }
+    }
+    // Entities that were bounded, or had their marker removed, are no longer tracked.
+    *unloaded_frames = still_unloaded;
+    derived_claims.entities.clear();
+}
+
+/// The entities whose bounding volume of type T was derived by [spawn_from_aabbs] this frame. The
+/// derived volume is only inserted once the stage ends, so the [spawn] system skips these entities
+/// instead of fitting them again.
+#[derive(Debug)]
+pub struct DerivedBoundsClaims<T: BoundingVolume + Send + Sync> {
+    entities: HashSet<Entity>,
+    marker: PhantomData<T>,
+}
+
+impl<T: BoundingVolume + Send + Sync> Default for DerivedBoundsClaims<T> {
+    fn default() -> Self {
+        DerivedBoundsClaims {
+            entities: HashSet::default(),
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Marks a bounding volume of type T that was derived from an [Aabb] by [spawn_from_aabbs], storing
+/// the mesh space ends of the diagonal of the box, which are the points that determined the volume.
+/// It is removed once the volume is refit to the mesh.
+#[derive(Debug, Clone, Copy, Component)]
+pub struct DerivedFromAabb<T: BoundingVolume + Send + Sync> {
+    pub diagonal: (Vec3, Vec3),
+    marker: PhantomData<T>,
+}
+
+impl<T: BoundingVolume + Send + Sync> DerivedFromAabb<T> {
+    pub fn new(diagonal: (Vec3, Vec3)) -> Self {
+        DerivedFromAabb {
+            diagonal,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Spawns both the [Aabb] and the bounding volume of type T of entities marked to have both
+/// generated, when T can be derived from the box with [BoundingVolume::derive_from_aabb], such as
+/// [BSphere](sphere::BSphere). This avoids fitting the mesh a second time, but the derived volume
+/// is looser than a volume fit to the mesh; once the mesh changes, it is refit to the mesh. The
+/// mesh, transform, and [Aabb] fit are resolved like in [spawn].
+///
+/// Entities are left to the `spawn` systems when they are marked with
+/// [ExactSphere](sphere::ExactSphere), or when the [FitBudget] of T selects [FitMethod::Precise]
+/// for their mesh, which asks for the tightest volume. This runs before the `spawn` systems, which
+/// skip the entities it claimed in the [DerivedBoundsClaims] of T and of [Aabb]. It is only added
+/// for types where [BoundingVolume::DERIVES_FROM_AABB] is true.
+#[allow(clippy::type_complexity)]
+pub fn spawn_from_aabbs<T: 'static + BoundingVolume + Send + Sync + Debug + Component>(
+    mut commands: Commands,
+    meshes: Res<Assets<Mesh>>,
+    decoder: Res<PositionDecoder>,
+    mut vertex_cache: ResMut<MeshVertexCache>,
+    fit_budget: Res<FitBudget<T>>,
+    aabb_fit_budget: Option<Res<FitBudget<Aabb>>>,
+    zero_scale_policy: Res<ZeroScalePolicy>,
+    mut derived_claims: ResMut<DerivedBoundsClaims<T>>,
+    mut aabb_claims: Option<ResMut<DerivedBoundsClaims<Aabb>>>,
+    query: Query<
+        (
+            &Handle<Mesh>,
+            &GlobalTransform,
+            Entity,
+            Option<&BoundsSourceAttribute>,
+            Option<&BoundsVertexRange>,
+            Option<&BoundsProxyPoints>,
+            Option<&LockBoundsToHandle>,
+            Option<&BoundsReferenceTransform>,
+        ),
+        (
+            With<Bounded<Aabb>>,
+            With<Bounded<T>>,
+            Without<sphere::ExactSphere>,
+            Without<ManualBounds>,
+            Without<BoundsMeshes>,
+        ),
+    >,
+) {
+    for (handle, transform, entity, attribute, range, proxy, lock, reference) in query.iter() {
+        let handle = LockBoundsToHandle::resolve(lock, handle);
+        let transform = BoundsReferenceTransform::resolve(reference, transform);
+        let transform = &match zero_scale_policy.apply(transform) {
+            Some(transform) => transform,
+            // The markers are left to the `spawn` systems, which wait for a valid scale.
+            None => continue,
+        };
+        if let Some(mesh) = meshes.get(handle) {
+            let source = VertexSource::with_attribute(mesh, attribute)
+                .with_range(range)
+                .with_proxy(proxy)
+                .with_decoder(&decoder);
+            let vertices = match vertex_cache.get_or_insert(handle, &source) {
+                Ok(vertices) => vertices,
+                Err(err) => {
+                    // Leave the markers to the `spawn` systems, which report the error.
+                    debug!("Could not derive bounds for entity {:?}: {}", entity, err);
+                    continue;
+                }
+            };
+            if fit_budget.method(vertices.len()) == Some(FitMethod::Precise) {
+                continue;
+            }
+            let (aabb, aabb_method) = match &aabb_fit_budget {
+                Some(aabb_fit_budget) => aabb_fit_budget.fit(vertices, transform),
+                None => (Aabb::from_vertices(vertices, transform), None),
+            };
+            // Types that can't be derived from a box are left to the `spawn` systems.
+            let derived = match T::derive_from_aabb(&aabb, transform) {
+                Some(derived) => derived,
+                None => continue,
+            };
+            derived_claims.entities.insert(entity);
+            if let Some(aabb_claims) = &mut aabb_claims {
+                aabb_claims.entities.insert(entity);
+            }
+            info!("New bounding volume generated: {:?}", aabb);
+            info!("New bounding volume derived from AABB: {:?}", derived);
+            let mut entity_commands = commands.entity(entity);
+            entity_commands
+                .insert(DerivedFromAabb::<T>::new(
+                    aabb.mesh_space_diagonal(transform),
+                ))
+                .insert(aabb)
+                .insert(derived)
+                .insert(BoundsPose::<Aabb>::new(transform))
+                .insert(BoundsPose::<T>::new(transform))
+                .remove::<Bounded<Aabb>>()
                 .remove::<Bounded<T>>();
+            if let Some(method) = aabb_method {
+                entity_commands.insert(ChosenFitMethod::<Aabb>::new(method));
+            }
+        }
+    }
+}
+
+/// Spawns a new [BoundingVolume] for entities marked with [Bounded] and [BoundsMeshes], once all of
+/// their meshes have loaded, by fitting a single volume to the vertices of every mesh.
+pub fn spawn_multi_mesh<T: 'static + BoundingVolume + Send + Sync + Debug + Component>(
+    mut commands: Commands,
+    meshes: Res<Assets<Mesh>>,
+    decoder: Res<PositionDecoder>,
+    fit_budget: Res<FitBudget<T>>,
+    zero_scale_policy: Res<ZeroScalePolicy>,
+    query: Query<
+        (
+            Entity,
+            &BoundsMeshes,
+            &GlobalTransform,
+            Option<&BoundsReferenceTransform>,
+        ),
+        (With<Bounded<T>>, Without<ManualBounds>),
+    >,
+) {
+    for (entity, bounds_meshes, transform, reference) in query.iter() {
+        let transform = BoundsReferenceTransform::resolve(reference, transform);
+        let transform = &match zero_scale_policy.apply(transform) {
+            Some(transform) => transform,
+            // The entity is bounded once its scale is valid again.
+            None => continue,
+        };
+        let vertices = match bounds_meshes.vertices(&meshes, &decoder) {
+            Some(Ok(vertices)) => vertices,
+            Some(Err(err)) => {
+                error!("Could not bound entity {:?}: {}", entity, err);
+                commands.entity(entity).remove::<Bounded<T>>();
+                continue;
+            }
+            None => continue,
+        };
+        let (new_bound, method) = fit_budget.fit(&vertices, transform);
+        info!("New bounding volume generated: {:?}", new_bound);
+        let mut entity_commands = commands.entity(entity);
+        entity_commands
+            .insert(new_bound)
+            .insert(BoundsPose::<T>::new(transform))
+            .remove::<Bounded<T>>();
+        if let Some(method) = method {
+            entity_commands.insert(ChosenFitMethod::<T>::new(method));
+        }
+    }
+}
+
+/// Computes the bounding volumes of every entity marked with [Bounded] in parallel, using the
+/// [ComputeTaskPool], and inserts them immediately. This is meant to be called directly during a
+/// loading screen, or from an exclusive system that handles the error, so that all static meshes
+/// are bounded up front instead of over the following frames by the `spawn` system.
+///
+/// Only entities whose meshes have loaded, and whose scale isn't collapsed (see
+/// [ZeroScalePolicy]), can be bounded, so this should be run once all meshes have loaded; any
+/// other entities are left marked with [Bounded] for the `spawn` system.
+///
+/// Returns [BoundingError::MissingResource] without bounding anything if there is no
+/// `Assets<Mesh>` resource. Entities that can't be bounded individually are logged and unmarked.
+pub fn compute_bounds_now<T: 'static + BoundingVolume + Send + Sync + Debug + Component>(
+    world: &mut World,
+) -> Result<(), BoundingError> {
+    let zero_scale_policy = world
+        .get_resource::<ZeroScalePolicy>()
+        .copied()
+        .unwrap_or_default();
+    #[allow(clippy::type_complexity)]
+    let pending: Vec<(
+        Entity,
+        Handle<Mesh>,
+        GlobalTransform,
+        Option<BoundsSourceAttribute>,
+        Option<BoundsVertexRange>,
+        Option<BoundsProxyPoints>,
+    )> = world
+        .query_filtered::<(
+            Entity,
+            &Handle<Mesh>,
+            &GlobalTransform,
+            Option<&BoundsSourceAttribute>,
+            Option<&BoundsVertexRange>,
+            Option<&BoundsProxyPoints>,
+            Option<&LockBoundsToHandle>,
+            Option<&BoundsReferenceTransform>,
+        ), (
+            With<Bounded<T>>,
+            Without<ManualBounds>,
+            Without<BoundsMeshes>,
+        )>()
+        .iter(world)
+        .filter_map(
+            |(entity, handle, transform, attribute, range, proxy, lock, reference)| {
+                Some((
+                    entity,
+                    LockBoundsToHandle::resolve(lock, handle).clone(),
+                    zero_scale_policy
+                        .apply(BoundsReferenceTransform::resolve(reference, transform))?,
+                    attribute.cloned(),
+                    range.cloned(),
+                    proxy.cloned(),
+                ))
+            },
+        )
+        .collect();
+    let task_pool = world
+        .get_resource::<ComputeTaskPool>()
+        .map(|pool| pool.0.clone())
+        .unwrap_or_else(TaskPool::new);
+    let meshes = world
+        .get_resource::<Assets<Mesh>>()
+        .ok_or(BoundingError::MissingResource("Assets<Mesh>"))?;
+    let decoder = &world
+        .get_resource::<PositionDecoder>()
+        .cloned()
+        .unwrap_or_default();
+    let fit_budget = &FitBudget::<T>::new(
+        world
+            .get_resource::<FitBudget<T>>()
+            .and_then(|budget| budget.vertices),
+    );
+    #[allow(clippy::type_complexity)]
+    let new_bounds: Vec<(
+        Entity,
+        GlobalTransform,
+        Result<(T, Option<FitMethod>), BoundingError>,
+    )> = task_pool.scope(|scope| {
+        for (entity, handle, transform, attribute, range, proxy) in pending.iter() {
+            if let Some(mesh) = meshes.get(handle) {
+                scope.spawn(async move {
+                    let source = VertexSource::with_attribute(mesh, attribute.as_ref())
+                        .with_range(range.as_ref())
+                        .with_proxy(proxy.as_ref())
+                        .with_decoder(decoder);
+                    let fit = source
+                        .vertices()
+                        .map(|vertices| fit_budget.fit(&vertices, transform));
+                    (*entity, *transform, fit)
+                });
+            }
+        }
+    });
+    for (entity, transform, fit) in new_bounds {
+        let (new_bound, method) = match fit {
+            Ok(fit) => fit,
+            Err(err) => {
+                error!("Could not bound entity {:?}: {}", entity, err);
+                world.entity_mut(entity).remove::<Bounded<T>>();
+                continue;
+            }
+        };
+        info!("New bounding volume generated: {:?}", new_bound);
+        let mut entity_mut = world.entity_mut(entity);
+        entity_mut
+            .insert(new_bound)
+            .insert(BoundsPose::<T>::new(&transform))
+            .remove::<Bounded<T>>();
+        if let Some(method) = method {
+            entity_mut.insert(ChosenFitMethod::<T>::new(method));
+        }
+    }
+    Ok(())
+}
+
+/// Recomputes every bounding volume of type T from its current mesh and [GlobalTransform],
+/// regardless of whether either has changed. This is meant to be run as an exclusive system, or
+/// called directly, in response to a global change that affects all meshes, such as switching the
+/// units of the app at runtime.
+pub fn rebuild_all<T: 'static + BoundingVolume + Send + Sync + Component>(world: &mut World) {
+    let zero_scale_policy = world
+        .get_resource::<ZeroScalePolicy>()
+        .copied()
+        .unwrap_or_default();
+    let fit_budget = FitBudget::<T>::new(
+        world
+            .get_resource::<FitBudget<T>>()
+            .and_then(|budget| budget.vertices),
+    );
+    let decoder = world
+        .get_resource::<PositionDecoder>()
+        .cloned()
+        .unwrap_or_default();
+    world.resource_scope(|world, meshes: Mut<Assets<Mesh>>| {
+        let mut rebuilt = Vec::new();
+        let mut query = world.query_filtered::<(
+            Entity,
+            &mut T,
+            &GlobalTransform,
+            &Handle<Mesh>,
+            Option<&BoundsSourceAttribute>,
+            Option<&BoundsVertexRange>,
+            Option<&BoundsProxyPoints>,
+            Option<&mut ChosenFitMethod<T>>,
+            Option<&LockBoundsToHandle>,
+            Option<&BoundsReferenceTransform>,
+        ), (Without<ManualBounds>, Without<BoundsMeshes>)>();
+        for (
+            entity,
+            mut bounding_vol,
+            transform,
+            handle,
+            attribute,
+            range,
+            proxy,
+            chosen_method,
+            lock,
+            reference,
+        ) in query.iter_mut(world)
+        {
+            let transform = BoundsReferenceTransform::resolve(reference, transform);
+            let transform = &match zero_scale_policy.apply(transform) {
+                Some(transform) => transform,
+                None => continue,
+            };
+            if let Some(mesh) = meshes.get(LockBoundsToHandle::resolve(lock, handle)) {
+                let source = VertexSource::with_attribute(mesh, attribute)
+                    .with_range(range)
+                    .with_proxy(proxy)
+                    .with_decoder(&decoder);
+                let vertices = match source.vertices() {
+                    Ok(vertices) => vertices,
+                    Err(err) => {
+                        error!("Could not rebuild a bounding volume: {}", err);
+                        continue;
+                    }
+                };
+                let (bound_vol, method) = fit_budget.fit(&vertices, transform);
+                *bounding_vol = bound_vol;
+                if let (Some(method), Some(mut chosen_method)) = (method, chosen_method) {
+                    chosen_method.method = method;
+                }
+                rebuilt.push((entity, BoundsPose::<T>::new(transform)));
+            }
+        }
+        let mut multi_mesh_query = world.query_filtered::<(
+            Entity,
+            &mut T,
+            &GlobalTransform,
+            &BoundsMeshes,
+            Option<&mut ChosenFitMethod<T>>,
+            Option<&BoundsReferenceTransform>,
+        ), Without<ManualBounds>>();
+        for (entity, mut bounding_vol, transform, bounds_meshes, chosen_method, reference) in
+            multi_mesh_query.iter_mut(world)
+        {
+            let transform = BoundsReferenceTransform::resolve(reference, transform);
+            let transform = &match zero_scale_policy.apply(transform) {
+                Some(transform) => transform,
+                None => continue,
+            };
+            let vertices = match bounds_meshes.vertices(&meshes, &decoder) {
+                Some(Ok(vertices)) => vertices,
+                Some(Err(err)) => {
+                    error!("Could not rebuild a bounding volume: {}", err);
+                    continue;
+                }
+                None => continue,
+            };
+            let (bound_vol, method) = fit_budget.fit(&vertices, transform);
+            *bounding_vol = bound_vol;
+            if let (Some(method), Some(mut chosen_method)) = (method, chosen_method) {
+                chosen_method.method = method;
+            }
+            rebuilt.push((entity, BoundsPose::<T>::new(transform)));
+        }
+        for (entity, pose) in rebuilt {
+            world
+                .entity_mut(entity)
+                .insert(pose)
+                .remove::<DerivedFromAabb<T>>();
+        }
+    });
+}
+
+/// Computes the bounding volume of type T of the mesh of `child`, expressed in the local frame of
+/// its ancestor `socket`, such as a weapon bounded at the attachment socket of a character. The
+/// transform of the child relative to the socket is resolved from their [GlobalTransform]s, and is
+/// returned along with the volume: it is the transform to use with the volume, in the frame of the
+/// socket.
+pub fn bounds_in_socket_frame<T: BoundingVolume>(
+    world: &World,
+    child: Entity,
+    socket: Entity,
+) -> Result<(T, GlobalTransform), BoundingError> {
+    let mut ancestor = world.get::<Parent>(child);
+    loop {
+        match ancestor {
+            Some(parent) if parent.0 == socket => break,
+            Some(parent) => ancestor = world.get::<Parent>(parent.0),
+            None => {
+                return Err(BoundingError::NotAnAncestor {
+                    entity: child,
+                    ancestor: socket,
+                })
+            }
+        }
+    }
+    let mesh = world
+        .get::<Handle<Mesh>>(child)
+        .and_then(|handle| world.get_resource::<Assets<Mesh>>()?.get(handle))
+        .ok_or(BoundingError::MeshNotFound(child))?;
+    let child_transform = world
+        .get::<GlobalTransform>(child)
+        .ok_or(BoundingError::MeshNotFound(child))?;
+    let socket_transform = world
+        .get::<GlobalTransform>(socket)
+        .ok_or(BoundingError::MeshNotFound(socket))?;
+    let relative = GlobalTransform::from_matrix(
+        socket_transform.compute_matrix().inverse() * child_transform.compute_matrix(),
+    );
+    let default_decoder = PositionDecoder::default();
+    let decoder = world
+        .get_resource::<PositionDecoder>()
+        .unwrap_or(&default_decoder);
+    Ok((
+        T::new(VertexSource::new(mesh).with_decoder(decoder), &relative)?,
+        relative,
+    ))
+}
+
+/// Updated bounding volumes are only written back if they differ from the existing bounding volume
+/// by more than this, so `Changed<T>` isn't triggered needlessly.
+pub(crate) const UPDATE_EPSILON: f32 = 1e-6;
+
+/// Writes `new_bound` to `bounding_vol` only if they differ by more than [UPDATE_EPSILON].
+fn write_if_changed<T: BoundingVolume + Component>(bounding_vol: &mut Mut<T>, new_bound: T) {
+    if !bounding_vol.approx_eq(&new_bound, UPDATE_EPSILON) {
+        **bounding_vol = new_bound;
+    }
+}
+
+/// Records that the bounding volume of type T on `entity` is up to date for `transform`, whether
+/// or not it was written.
+fn record_pose<T: 'static + BoundingVolume + Send + Sync>(
+    commands: &mut Commands,
+    entity: Entity,
+    pose: Option<Mut<BoundsPose<T>>>,
+    transform: &GlobalTransform,
+) {
+    match pose {
+        Some(mut pose) => {
+            if !pose.matches(transform) {
+                *pose = BoundsPose::new(transform);
+            }
+        }
+        None => {
+            commands
+                .entity(entity)
+                .insert(BoundsPose::<T>::new(transform));
         }
     }
 }
 
-/// Updates [BoundingVolume]s when their meshes or [GlobalTransform]s are changed. If an entity's
-/// mesh has changed, triggering a bounding volume update, the update function will won't update it
-/// a second time if the transform has also changed.
+/// Updates [BoundingVolume]s when their meshes or [GlobalTransform]s are changed. A mesh is
+/// considered changed if the entity's mesh handle is changed, or if the mesh asset itself is
+/// modified in place, which is the only case where volumes that don't depend on the transform,
+/// such as [obb::Obb], are recomputed. If an entity's mesh has changed, triggering a bounding
+/// volume update, the update function won't update it a second time if the transform has also
+/// changed. Bounding volumes are only written to when they actually change, so `Changed<T>` can be
+/// relied on by other systems, and the [BoundsPose] they were last updated for is tracked so a
+/// transform change that doesn't change the rotation or scale doesn't refit them. Entities bounded
+/// by [BoundsMeshes] are updated by [update_multi_mesh] instead.
+#[allow(clippy::type_complexity)]
 fn update<T: 'static + BoundingVolume + Send + Sync + Component>(
+    mut commands: Commands,
     meshes: Res<Assets<Mesh>>,
-    changed_mesh_query: Query<Entity, Changed<Handle<Mesh>>>,
-    changed_transform_query: Query<Entity, Changed<GlobalTransform>>,
-    mut bound_vol_query: Query<(&mut T, &GlobalTransform, &Handle<Mesh>)>,
+    decoder: Res<PositionDecoder>,
+    fit_budget: Res<FitBudget<T>>,
+    zero_scale_policy: Res<ZeroScalePolicy>,
+    mut shared_vertices: ResMut<SharedVertexCache>,
+    mut mesh_events: EventReader<AssetEvent<Mesh>>,
+    mesh_handle_query: Query<
+        (Entity, &Handle<Mesh>, Option<&LockBoundsToHandle>),
+        (With<T>, Without<BoundsMeshes>),
+    >,
+    // The rendered mesh of an entity with a locked handle doesn't affect its bounds.
+    changed_mesh_query: Query<Entity, (Changed<Handle<Mesh>>, Without<LockBoundsToHandle>)>,
+    changed_source_query: Query<
+        Entity,
+        Or<(
+            Changed<BoundsProxyPoints>,
+            Changed<LockBoundsToHandle>,
+            Changed<BoundsReferenceTransform>,
+        )>,
+    >,
+    // The bounds of an entity with a reference transform don't depend on its own transform.
+    changed_transform_query: Query<
+        Entity,
+        (Changed<GlobalTransform>, Without<BoundsReferenceTransform>),
+    >,
+    // Derived volumes are no longer derived once they are refit.
+    derived_query: Query<(), With<DerivedFromAabb<T>>>,
+    mut bound_vol_query: Query<
+        (
+            &mut T,
+            &GlobalTransform,
+            &Handle<Mesh>,
+            Option<&BoundsSourceAttribute>,
+            Option<&BoundsVertexRange>,
+            Option<&BoundsProxyPoints>,
+            Option<&mut ChosenFitMethod<T>>,
+            Option<&LockBoundsToHandle>,
+            Option<&BoundsReferenceTransform>,
+            Option<&mut BoundsPose<T>>,
+            Option<&SharedBounds>,
+        ),
+        (Without<ManualBounds>, Without<BoundsMeshes>),
+    >,
 ) {
-    for entity in changed_mesh_query.iter() {
-        if let Ok((mut bounding_vol, transform, handle)) = bound_vol_query.get_mut(entity) {
+    let modified_meshes: HashSet<HandleId> = mesh_events
+        .iter()
+        .filter_map(|event| match event {
+            AssetEvent::Modified { handle } => Some(handle.id),
+            _ => None,
+        })
+        .collect();
+    let mut changed_mesh_entities: Vec<Entity> = changed_mesh_query
+        .iter()
+        .chain(changed_source_query.iter())
+        .collect();
+    if !modified_meshes.is_empty() {
+        changed_mesh_entities.extend(
+            mesh_handle_query
+                .iter()
+                .filter(|(_, handle, lock)| {
+                    modified_meshes.contains(&LockBoundsToHandle::resolve(*lock, handle).id)
+                })
+                .map(|(entity, _, _)| entity),
+        );
+    }
+    let mut refit = HashSet::default();
+    for entity in changed_mesh_entities {
+        // An entity can have several changes at once, but only needs to be refit once.
+        if !refit.insert(entity) {
+            continue;
+        }
+        if let Ok((
+            mut bounding_vol,
+            transform,
+            handle,
+            attribute,
+            range,
+            proxy,
+            chosen_method,
+            lock,
+            reference,
+            pose,
+            shared,
+        )) = bound_vol_query.get_mut(entity)
+        {
+            let transform = BoundsReferenceTransform::resolve(reference, transform);
+            // The last valid bounding volume is kept while the scale is collapsed.
+            let transform = &match zero_scale_policy.apply(transform) {
+                Some(transform) => transform,
+                None => continue,
+            };
+            let handle = LockBoundsToHandle::resolve(lock, handle);
             if let Some(mesh) = meshes.get(handle) {
-                *bounding_vol = T::new(mesh, transform);
+                let source = VertexSource::with_attribute(mesh, attribute)
+                    .with_range(range)
+                    .with_proxy(proxy)
+                    .with_decoder(&decoder);
+                // The shared vertices of a modified mesh were invalidated before this runs.
+                let vertices = match shared {
+                    Some(_) => shared_vertices
+                        .get_or_insert(handle, &source)
+                        .map(Cow::Borrowed),
+                    None => source.vertices().map(Cow::Owned),
+                };
+                let vertices = match vertices {
+                    Ok(vertices) => vertices,
+                    Err(err) => {
+                        error!(
+                            "Could not update the bounds of entity {:?}: {}",
+                            entity, err
+                        );
+                        continue;
+                    }
+                };
+                let (bound_vol, method) = fit_budget.fit(&vertices, transform);
+                write_if_changed(&mut bounding_vol, bound_vol);
+                record_pose(&mut commands, entity, pose, transform);
+                if derived_query.get(entity).is_ok() {
+                    commands.entity(entity).remove::<DerivedFromAabb<T>>();
+                }
+                if let (Some(method), Some(mut chosen_method)) = (method, chosen_method) {
+                    if chosen_method.method != method {
+                        chosen_method.method = method;
+                    }
+                }
             }
         }
     }
     for entity in changed_transform_query.iter() {
-        // Only process entities that haven't already been updated.
-        if changed_mesh_query.get(entity).is_err() {
-            if let Ok((mut bounding_vol, transform, handle)) = bound_vol_query.get_mut(entity) {
-                if let Some(mesh) = meshes.get(handle) {
-                    if let Some(bound_vol) =
-                        bounding_vol.update_on_transform_change(mesh, transform)
-                    {
-                        *bounding_vol = bound_vol;
+        // Only process entities that haven't already been refit.
+        if refit.contains(&entity) {
+            continue;
+        }
+        if let Ok((
+            mut bounding_vol,
+            transform,
+            handle,
+            attribute,
+            range,
+            proxy,
+            _,
+            lock,
+            _,
+            pose,
+            shared,
+        )) = bound_vol_query.get_mut(entity)
+        {
+            let transform = &match zero_scale_policy.apply(transform) {
+                Some(transform) => transform,
+                None => continue,
+            };
+            // A translation alone never changes the volume.
+            if pose.as_ref().map_or(false, |pose| pose.matches(transform)) {
+                continue;
+            }
+            let handle = LockBoundsToHandle::resolve(lock, handle);
+            if let Some(mesh) = meshes.get(handle) {
+                let source = VertexSource::with_attribute(mesh, attribute)
+                    .with_range(range)
+                    .with_proxy(proxy)
+                    .with_decoder(&decoder);
+                // Instances of a shared mesh are refit from its shared vertices, which are only
+                // read once.
+                let new_bound = match shared {
+                    Some(_) => match shared_vertices.get_or_insert(handle, &source) {
+                        Ok(vertices) => bounding_vol.update_on_transform_change(
+                            &VertexSource::new(mesh).with_points(vertices),
+                            transform,
+                        ),
+                        Err(err) => {
+                            error!(
+                                "Could not update the bounds of entity {:?}: {}",
+                                entity, err
+                            );
+                            continue;
+                        }
+                    },
+                    None => bounding_vol.update_on_transform_change(&source, transform),
+                };
+                if let Some(bound_vol) = new_bound {
+                    write_if_changed(&mut bounding_vol, bound_vol);
+                    if derived_query.get(entity).is_ok() {
+                        commands.entity(entity).remove::<DerivedFromAabb<T>>();
                     }
                 }
+                record_pose(&mut commands, entity, pose, transform);
+            }
+        }
+    }
+}
+
+/// Updates the [BoundingVolume]s of entities bounded by [BoundsMeshes], like [update] does for
+/// entities bounded by their own mesh. The volume is refit when the list of meshes or the
+/// [BoundsReferenceTransform] changes, or when any of the meshes is modified in place, and is
+/// otherwise only updated for a change of the rotation or scale of the [GlobalTransform].
+#[allow(clippy::type_complexity)]
+fn update_multi_mesh<T: 'static + BoundingVolume + Send + Sync + Component>(
+    mut commands: Commands,
+    meshes: Res<Assets<Mesh>>,
+    decoder: Res<PositionDecoder>,
+    fit_budget: Res<FitBudget<T>>,
+    zero_scale_policy: Res<ZeroScalePolicy>,
+    mut mesh_events: EventReader<AssetEvent<Mesh>>,
+    bounds_meshes_query: Query<(Entity, &BoundsMeshes), With<T>>,
+    changed_source_query: Query<
+        Entity,
+        (
+            With<BoundsMeshes>,
+            Or<(Changed<BoundsMeshes>, Changed<BoundsReferenceTransform>)>,
+        ),
+    >,
+    changed_transform_query: Query<
+        Entity,
+        (
+            With<BoundsMeshes>,
+            Changed<GlobalTransform>,
+            Without<BoundsReferenceTransform>,
+        ),
+    >,
+    mut bound_vol_query: Query<
+        (
+            &mut T,
+            &GlobalTransform,
+            &BoundsMeshes,
+            Option<&mut ChosenFitMethod<T>>,
+            Option<&BoundsReferenceTransform>,
+            Option<&mut BoundsPose<T>>,
+        ),
+        Without<ManualBounds>,
+    >,
+) {
+    let modified_meshes: HashSet<HandleId> = mesh_events
+        .iter()
+        .filter_map(|event| match event {
+            AssetEvent::Modified { handle } => Some(handle.id),
+            _ => None,
+        })
+        .collect();
+    let mut changed_mesh_entities: Vec<Entity> = changed_source_query.iter().collect();
+    if !modified_meshes.is_empty() {
+        changed_mesh_entities.extend(
+            bounds_meshes_query
+                .iter()
+                .filter(|(_, bounds_meshes)| bounds_meshes.contains_any(&modified_meshes))
+                .map(|(entity, _)| entity),
+        );
+    }
+    let mut refit = HashSet::default();
+    for entity in changed_mesh_entities {
+        if !refit.insert(entity) {
+            continue;
+        }
+        if let Ok((mut bounding_vol, transform, bounds_meshes, chosen_method, reference, pose)) =
+            bound_vol_query.get_mut(entity)
+        {
+            let transform = BoundsReferenceTransform::resolve(reference, transform);
+            let transform = &match zero_scale_policy.apply(transform) {
+                Some(transform) => transform,
+                None => continue,
+            };
+            let vertices = match bounds_meshes.vertices(&meshes, &decoder) {
+                Some(Ok(vertices)) => vertices,
+                Some(Err(err)) => {
+                    error!(
+                        "Could not update the bounds of entity {:?}: {}",
+                        entity, err
+                    );
+                    continue;
+                }
+                None => continue,
+            };
+            let (bound_vol, method) = fit_budget.fit(&vertices, transform);
+            write_if_changed(&mut bounding_vol, bound_vol);
+            record_pose(&mut commands, entity, pose, transform);
+            if let (Some(method), Some(mut chosen_method)) = (method, chosen_method) {
+                if chosen_method.method != method {
+                    chosen_method.method = method;
+                }
+            }
+        }
+    }
+    for entity in changed_transform_query.iter() {
+        if refit.contains(&entity) {
+            continue;
+        }
+        if let Ok((mut bounding_vol, transform, bounds_meshes, _, _, pose)) =
+            bound_vol_query.get_mut(entity)
+        {
+            let transform = &match zero_scale_policy.apply(transform) {
+                Some(transform) => transform,
+                None => continue,
+            };
+            if pose.as_ref().map_or(false, |pose| pose.matches(transform)) {
+                continue;
+            }
+            // The source needs a mesh, but only the concatenated points are read.
+            let first_mesh = match bounds_meshes
+                .0
+                .first()
+                .and_then(|handle| meshes.get(handle))
+            {
+                Some(mesh) => mesh,
+                None => continue,
+            };
+            let vertices = match bounds_meshes.vertices(&meshes, &decoder) {
+                Some(Ok(vertices)) => vertices,
+                Some(Err(err)) => {
+                    error!(
+                        "Could not update the bounds of entity {:?}: {}",
+                        entity, err
+                    );
+                    continue;
+                }
+                None => continue,
+            };
+            let source = VertexSource::new(first_mesh).with_points(&vertices);
+            if let Some(bound_vol) = bounding_vol.update_on_transform_change(&source, transform) {
+                write_if_changed(&mut bounding_vol, bound_vol);
             }
+            record_pose(&mut commands, entity, pose, transform);
         }
     }
 }
@@ -0,0 +1,69 @@
+use crate::BoundingError;
+use bevy::{
+    prelude::*,
+    render::{
+        mesh::{Indices, VertexAttributeValues},
+        pipeline::PrimitiveTopology,
+    },
+};
+
+/// Builds a [PrimitiveTopology::LineList] mesh with a line for each pair of edge endpoints.
+pub(crate) fn line_list_mesh(edges: &[(Vec3, Vec3)]) -> Mesh {
+    let vertices: Vec<[f32; 3]> = edges
+        .iter()
+        .flat_map(|(start, end)| [[start.x, start.y, start.z], [end.x, end.y, end.z]])
+        .collect();
+    let indices = Indices::U32((0..vertices.len() as u32).collect());
+
+    let mut mesh = Mesh::new(PrimitiveTopology::LineList);
+    mesh.set_attribute(Mesh::ATTRIBUTE_POSITION, vertices.clone());
+    mesh.set_attribute(Mesh::ATTRIBUTE_UV_0, vertices.clone());
+    mesh.set_attribute(Mesh::ATTRIBUTE_NORMAL, vertices);
+    mesh.set_indices(Some(indices));
+    mesh
+}
+
+/// Builds a solid [PrimitiveTopology::TriangleList] mesh of a box, given its 8 vertices in the
+/// order of [crate::aabb::Aabb::vertices_mesh_space].
+pub(crate) fn box_triangle_mesh(box_vertices: &[Vec3; 8]) -> Mesh {
+    let vertices: Vec<[f32; 3]> = box_vertices
+        .iter()
+        .map(|vert| [vert.x, vert.y, vert.z])
+        .collect();
+
+    #[rustfmt::skip]
+    let indices = Indices::U32(vec![
+        0, 3, 2, 0, 2, 1, // Top
+        4, 5, 6, 4, 6, 7, // Bottom
+        0, 1, 5, 0, 5, 4, // Front
+        3, 7, 6, 3, 6, 2, // Back
+        0, 4, 7, 0, 7, 3, // Right
+        1, 2, 6, 1, 6, 5, // Left
+    ]);
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+    mesh.set_attribute(Mesh::ATTRIBUTE_POSITION, vertices.clone());
+    mesh.set_attribute(Mesh::ATTRIBUTE_UV_0, vertices.clone());
+    mesh.set_attribute(Mesh::ATTRIBUTE_NORMAL, vertices);
+    mesh.set_indices(Some(indices));
+    mesh
+}
+
+/// Applies `transform` to every vertex position of the mesh.
+pub(crate) fn transform_positions(mesh: &mut Mesh, transform: Mat4) -> Result<(), BoundingError> {
+    match mesh.attribute_mut(Mesh::ATTRIBUTE_POSITION) {
+        None => Err(BoundingError::MissingAttribute(
+            Mesh::ATTRIBUTE_POSITION.into(),
+        )),
+        Some(VertexAttributeValues::Float32x3(ref mut positions)) => {
+            *positions = positions
+                .iter()
+                .map(|coordinates| transform.transform_point3(Vec3::from(*coordinates)).into())
+                .collect();
+            Ok(())
+        }
+        Some(_) => Err(BoundingError::UnexpectedAttributeType(
+            Mesh::ATTRIBUTE_POSITION.into(),
+        )),
+    }
+}
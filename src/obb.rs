@@ -1,12 +1,32 @@
-use crate::aabb::Aabb;
-use crate::BoundingVolume;
-use bevy::{
-    prelude::*,
-    render::{mesh::VertexAttributeValues, pipeline::PrimitiveTopology},
+use crate::aabb::{box_edges, Aabb, BoxCorners};
+use crate::bytes::{floats_from_bytes, floats_to_bytes};
+use crate::intersect::{self, project_vertices, sphere_intersects_cone};
+use crate::mesh::{box_triangle_mesh, line_list_mesh};
+#[cfg(feature = "debug")]
+use crate::{
+    debug::{debug_renderer_present, update_debug_obb_axes},
+    BoundingSystem,
 };
-use core::panic;
+use crate::{
+    vertex::{finite_vertices, VertexSource},
+    BoundingError, BoundingKind, BoundingVolume, DebugMeshOptions, DebugMeshTopology, FitMethod,
+};
+use bevy::{prelude::*, tasks::TaskPool};
 use std::{convert::TryInto, f32::consts::PI};
 
+/// The default angular step, in degrees, of the orientation sweep used to fit an [Obb].
+const DEFAULT_ANGULAR_STEP: f32 = 15.0;
+/// The finest angular step accepted by [Obb::new_with_steps], in degrees.
+const MIN_ANGULAR_STEP: f32 = 0.01;
+/// The widest range of angles swept about an axis, in degrees.
+const MAX_SWEEP_RANGE: f32 = 90.0;
+/// The most angles swept about an axis, which the [MIN_ANGULAR_STEP] gives over the
+/// [MAX_SWEEP_RANGE].
+const MAX_SWEEP_ANGLES: usize = 9000;
+/// The angular steps, in degrees, used for [FitMethod::Precise] and [FitMethod::Fast] fits.
+const PRECISE_ANGULAR_STEP: f32 = 5.0;
+const FAST_ANGULAR_STEP: f32 = 30.0;
+
 /// Defines a bounding box, oriented to minimize the bounded volume. This bounding box is expensive
 /// to compute, but cheap to update.
 ///
@@ -26,7 +46,7 @@ pub struct Obb {
     ///
     /// ## Note
     /// This is *not* the orientation of the bounding box! You probably want the conjugate of
-    /// this quaternion if that's what you need.
+    /// this quaternion if that's what you need, see [Obb::orientation] and [Obb::mesh_to_box].
     mesh_orientation: Quat,
 }
 
@@ -45,10 +65,32 @@ impl Obb {
             .try_into()
             .unwrap()
     }
+    /// Returns an array of the 8 vertices of the bounding box in mesh space, which are the
+    /// vertices of [Obb::mesh_aabb] rotated by [Obb::orientation].
     pub fn vertices_mesh_space(&self) -> [Vec3; 8] {
-        let orient = Mat4::from_quat(self.orientation());
-        let transform = GlobalTransform::from_matrix(orient);
-        self.aabb.vertices(transform)
+        let orientation = self.orientation();
+        let mut vertices = self.aabb.vertices_mesh_space();
+        for vertex in vertices.iter_mut() {
+            *vertex = orientation * *vertex;
+        }
+        vertices
+    }
+    /// Returns the corners of the bounding box in world space, given the current mesh transform.
+    pub fn corners(&self, transform: &GlobalTransform) -> BoxCorners {
+        BoxCorners::new(self.vertices(*transform))
+    }
+    /// Returns the corners of the bounding box in mesh space.
+    pub fn corners_mesh_space(&self) -> BoxCorners {
+        BoxCorners::new(self.vertices_mesh_space())
+    }
+    /// Returns the 12 edges of the bounding box in world space, given the current mesh transform,
+    /// as pairs of edge endpoints.
+    pub fn edges(&self, transform: &GlobalTransform) -> [(Vec3, Vec3); 12] {
+        box_edges(&self.vertices(*transform))
+    }
+    /// Returns the 12 edges of the bounding box in mesh space, as pairs of edge endpoints.
+    pub fn edges_mesh_space(&self) -> [(Vec3, Vec3); 12] {
+        box_edges(&self.vertices_mesh_space())
     }
     pub fn from_aabb_orientation(aabb: Aabb, mesh_orientation: Quat) -> Obb {
         Obb {
@@ -56,6 +98,38 @@ impl Obb {
             mesh_orientation,
         }
     }
+    /// Returns the box as a flat binary blob, for precomputed bounds that are loaded without
+    /// parsing. The layout is the minimums then the maximums of [Obb::mesh_aabb], each as x, y, z,
+    /// then the quaternion returned by [Obb::orientation] as x, y, z, w, for 10 little-endian
+    /// `f32`s in total.
+    pub fn to_bytes(&self) -> [u8; 40] {
+        let (minimums, maximums) = (self.aabb.minimums(), self.aabb.maximums());
+        let orientation = self.orientation();
+        floats_to_bytes([
+            minimums.x,
+            minimums.y,
+            minimums.z,
+            maximums.x,
+            maximums.y,
+            maximums.z,
+            orientation.x,
+            orientation.y,
+            orientation.z,
+            orientation.w,
+        ])
+    }
+    /// Reads a box written by [Obb::to_bytes]. Fails if `bytes` isn't 40 bytes long.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Obb, BoundingError> {
+        let [min_x, min_y, min_z, max_x, max_y, max_z, x, y, z, w]: [f32; 10] =
+            floats_from_bytes(bytes)?;
+        Ok(Obb::from_aabb_orientation(
+            Aabb::from_extents(
+                Vec3::new(min_x, min_y, min_z),
+                Vec3::new(max_x, max_y, max_z),
+            ),
+            Quat::from_xyzw(x, y, z, w).conjugate(),
+        ))
+    }
     /// Returns the [AxisAlignedBB] of this [OrientedBB] in ***mesh space***.
     pub fn mesh_aabb(&self) -> &Aabb {
         &self.aabb
@@ -68,6 +142,42 @@ impl Obb {
     pub fn orientation(&self) -> Quat {
         self.mesh_orientation.conjugate()
     }
+    /// Returns the three axes of the bounding box in world space, as unit vectors in x, y, z order,
+    /// along with the half-extents of the box along each of them, given the current mesh
+    /// transform. The axes are orthonormal as long as the scale of the transform is uniform; with a
+    /// non-uniform scale, the transformed box is no longer a box.
+    pub fn axes_and_half_extents(&self, transform: &GlobalTransform) -> ([Vec3; 3], Vec3) {
+        let half_extents = (self.aabb.maximums() - self.aabb.minimums()) * 0.5;
+        let mut axes = [Vec3::X, Vec3::Y, Vec3::Z];
+        let mut world_half_extents = Vec3::ZERO;
+        for (index, axis) in axes.iter_mut().enumerate() {
+            let world_axis = transform.rotation * (self.orientation() * *axis * transform.scale);
+            let length = world_axis.length();
+            world_half_extents[index] = half_extents[index] * length;
+            *axis = world_axis / length;
+        }
+        (axes, world_half_extents)
+    }
+    /// Returns the bounding box in world space as a center, rotation, and half-extents, the form
+    /// used by most physics libraries, given the current mesh transform. A point `p` in the frame
+    /// of the box, within `-half_extents` to `half_extents`, is at `center + rotation * p` in world
+    /// space. Like [Obb::axes_and_half_extents], this is only exact for a uniform scale.
+    pub fn isometry(&self, transform: &GlobalTransform) -> (Vec3, Quat, Vec3) {
+        let box_center = (self.aabb.minimums() + self.aabb.maximums()) * 0.5;
+        let center = *transform * (self.orientation() * box_center);
+        let rotation = transform.rotation * self.orientation();
+        let (_, half_extents) = self.axes_and_half_extents(transform);
+        (center, rotation, half_extents)
+    }
+    /// Returns the rotation from mesh space into the frame of the bounding box, where the box is
+    /// [Obb::mesh_aabb]. This is the inverse of [Obb::orientation]:
+    ///
+    /// - `obb.mesh_to_box() * mesh_point` lies within [Obb::mesh_aabb] for every vertex of the
+    ///   mesh.
+    /// - `obb.orientation() * aabb_corner` is a corner of the box around the mesh, in mesh space.
+    pub fn mesh_to_box(&self) -> Quat {
+        self.mesh_orientation
+    }
     /// Returns an [AxisAlignedBB] that contains this [OrientedBB]. In other words, this returns
     /// the AABB of this OBB.
     ///
@@ -78,6 +188,9 @@ impl Obb {
     /// when the mesh changes, but we still get a bounding box that is aligned to the world axes.
     /// This comes with a tradeoff - because we are finding the AABB of the OBB, the bounding box
     /// will be more conservative, and will be larger than the AABB of the mesh itself.
+    ///
+    /// The returned box is in mesh space, rotated but not scaled or translated. To get the box in
+    /// world space, use [BoundingVolume::world_aabb].
     pub fn outer_aabb(&self) -> Aabb {
         let axis_aligned_vertices = self.aabb.vertices_mesh_space();
         let oriented_vertices: Vec<Vec3> = axis_aligned_vertices
@@ -86,6 +199,210 @@ impl Obb {
             .collect();
         Aabb::compute_aabb(&oriented_vertices)
     }
+    /// Returns true iff the world space outer AABBs of the two boxes overlap, see
+    /// [BoundingVolume::world_aabb]. This is a cheap, conservative pre-filter for
+    /// [BoundingVolume::intersects]: if it returns false, the boxes are certainly separated, and if
+    /// it returns true, the full separating axis test is needed to tell.
+    pub fn maybe_intersects(
+        &self,
+        transform: &GlobalTransform,
+        other: &Obb,
+        other_transform: &GlobalTransform,
+    ) -> bool {
+        // World space AABBs are used with an identity transform.
+        let identity = GlobalTransform::identity();
+        intersect::aabb_aabb(
+            &self.world_aabb(transform),
+            &identity,
+            &other.world_aabb(other_transform),
+            &identity,
+        )
+    }
+    /// Returns the volume of the bounding box in world space, given the current mesh transform.
+    pub fn volume(&self, transform: &GlobalTransform) -> f32 {
+        let scale = transform.scale;
+        self.aabb.volume() * (scale.x * scale.y * scale.z).abs()
+    }
+    /// Returns true iff the bounding box may intersect the cone with its tip at `apex`, opening
+    /// along `dir` with the given `half_angle` in radians, and capped at `range` along `dir`. This
+    /// is conservative: the sphere around the world space AABB of the box is tested, see
+    /// [BSphere::intersects_cone](crate::sphere::BSphere::intersects_cone).
+    pub fn intersects_cone(
+        &self,
+        transform: &GlobalTransform,
+        apex: Vec3,
+        dir: Vec3,
+        half_angle: f32,
+        range: f32,
+    ) -> bool {
+        let aabb = self.world_aabb(transform);
+        sphere_intersects_cone(
+            (aabb.minimums() + aabb.maximums()) * 0.5,
+            (aabb.maximums() - aabb.minimums()).length() * 0.5,
+            apex,
+            dir,
+            half_angle,
+            range,
+        )
+    }
+    /// Returns true iff the line segment from `a` to `b`, in world space, intersects the bounding
+    /// box. This includes the case where the segment lies entirely inside the box.
+    pub fn segment_intersects(&self, transform: &GlobalTransform, a: Vec3, b: Vec3) -> bool {
+        intersect::segment_obb(self, transform, a, b)
+    }
+    /// Fits an oriented bounding box to the mesh, using `hint` as the expected orientation of the
+    /// box in mesh space, in the same convention as [Obb::orientation]. Instead of sweeping all
+    /// orientations, only a local refinement of +/- 10 degrees around the hint is searched. This is
+    /// faster, and gives more predictable results for meshes with known "natural" axes.
+    pub fn new_with_hint<'a>(
+        source: impl Into<VertexSource<'a>>,
+        _transform: &GlobalTransform,
+        hint: Quat,
+    ) -> Result<Obb, BoundingError> {
+        let vertices = source.into().vertices()?;
+        // Ties keep the first candidate, so the angles are ordered by magnitude to prefer the
+        // smallest rotation away from the hint.
+        let angles = [0.0, -5.0, 5.0, -10.0, 10.0];
+        Ok(Obb::search(
+            &vertices,
+            hint.conjugate(),
+            &angles,
+            &angles,
+            None,
+        ))
+    }
+    /// Fits an oriented bounding box to the mesh, sweeping orientations in increments of
+    /// `angular_step_deg` degrees instead of the default of 15 degrees.
+    ///
+    /// ## Tradeoff
+    /// The fit time is proportional to the number of candidate orientations, so halving the step
+    /// doubles the fit time. In return, the best orientation found is within half a step of the
+    /// best orientation of the sweep, so a finer step gives a tighter box. A coarse step is
+    /// appropriate when loading many meshes, a fine step when a tight box matters more.
+    ///
+    /// Returns [BoundingError::InvalidParameter] if the step is finer than 0.01 degrees, or
+    /// coarser than the 90 degree range of the sweep.
+    pub fn new_with_steps<'a>(
+        source: impl Into<VertexSource<'a>>,
+        _transform: &GlobalTransform,
+        angular_step_deg: f32,
+    ) -> Result<Obb, BoundingError> {
+        if !(MIN_ANGULAR_STEP..=MAX_SWEEP_RANGE).contains(&angular_step_deg) {
+            return Err(BoundingError::InvalidParameter(format!(
+                "The angular step must be in the range [{}, {}] degrees, got {}",
+                MIN_ANGULAR_STEP, MAX_SWEEP_RANGE, angular_step_deg
+            )));
+        }
+        Ok(Obb::sweep(
+            &source.into().vertices()?,
+            angular_step_deg,
+            None,
+        ))
+    }
+    /// Fits an oriented bounding box to the mesh like [BoundingVolume::new], but fits the box for
+    /// each candidate orientation of the sweep as a separate task on the `task_pool`, such as the
+    /// [ComputeTaskPool](bevy::tasks::ComputeTaskPool). The result is identical to
+    /// [BoundingVolume::new]. Each candidate is a full pass over the vertices, so this scales with
+    /// the number of cores for high-poly meshes.
+    pub fn new_parallel<'a>(
+        source: impl Into<VertexSource<'a>>,
+        _transform: &GlobalTransform,
+        task_pool: &TaskPool,
+    ) -> Result<Obb, BoundingError> {
+        Ok(Obb::sweep(
+            &source.into().vertices()?,
+            DEFAULT_ANGULAR_STEP,
+            Some(task_pool),
+        ))
+    }
+    /// Rotates about the y-axis (turntable) over 45 degrees, then about the x-axis over 90
+    /// degrees, in increments of `angular_step_deg`, until the smallest volume box is found.
+    fn sweep(vertices: &[Vec3], angular_step_deg: f32, task_pool: Option<&TaskPool>) -> Obb {
+        let angles = |range: f32| -> Vec<f32> {
+            let count = ((range / angular_step_deg).ceil() as usize).min(MAX_SWEEP_ANGLES);
+            (0..count)
+                .map(|step| step as f32 * angular_step_deg)
+                .collect()
+        };
+        Obb::search(
+            vertices,
+            Quat::IDENTITY,
+            &angles(MAX_SWEEP_RANGE / 2.0),
+            &angles(MAX_SWEEP_RANGE),
+            task_pool,
+        )
+    }
+    /// Starting from the mesh orientation `seed`, rotates the mesh about the y-axis by each of the
+    /// `y_angles`, then about the x-axis by each of the `x_angles` (in degrees), and returns the
+    /// smallest volume bounding box found. Non-finite vertices are skipped; if there are no finite
+    /// vertices, an empty box at the origin is returned.
+    ///
+    /// The extents of each candidate don't depend on the order of the vertices, and a candidate
+    /// only replaces the best box if it is strictly smaller, so ties are always resolved in favor
+    /// of the candidate that comes first in `y_angles` and `x_angles`. This makes the fit
+    /// deterministic, regardless of how the mesh is authored.
+    ///
+    /// The candidates for each axis are fit in parallel on the `task_pool`, if there is one.
+    fn search(
+        vertices: &[Vec3],
+        seed: Quat,
+        y_angles: &[f32],
+        x_angles: &[f32],
+        task_pool: Option<&TaskPool>,
+    ) -> Obb {
+        let vertices = &finite_vertices(vertices);
+        if vertices.is_empty() {
+            return Obb::from_aabb_orientation(Aabb::from_extents(Vec3::ZERO, Vec3::ZERO), seed);
+        }
+        let y_orientations: Vec<Quat> = std::iter::once(seed)
+            .chain(
+                y_angles
+                    .iter()
+                    .map(|angle| seed * Quat::from_rotation_y(angle * PI / 180.0)),
+            )
+            .collect();
+        let orientation = Obb::smallest(vertices, &y_orientations, task_pool).mesh_orientation;
+        let x_orientations: Vec<Quat> = std::iter::once(orientation)
+            .chain(
+                x_angles
+                    .iter()
+                    .map(|angle| orientation * Quat::from_rotation_x(angle * PI / 180.0)),
+            )
+            .collect();
+        Obb::smallest(vertices, &x_orientations, task_pool)
+    }
+    /// Fits a box to the vertices in each of the mesh `orientations`, in parallel on the
+    /// `task_pool` if there is one, and returns the box with the smallest volume. Of boxes with
+    /// equal volumes, the one that comes first in `orientations` is returned.
+    fn smallest(vertices: &[Vec3], orientations: &[Quat], task_pool: Option<&TaskPool>) -> Obb {
+        let candidates: Vec<Obb> = match task_pool {
+            // The results of a scope are returned in the order the tasks were spawned.
+            Some(task_pool) => task_pool.scope(|scope| {
+                for &orientation in orientations {
+                    scope.spawn(async move { Obb::compute_obb(vertices, orientation) });
+                }
+            }),
+            None => orientations
+                .iter()
+                .map(|&orientation| Obb::compute_obb(vertices, orientation))
+                .collect(),
+        };
+        let volume_of = |obb: &Obb| {
+            let diff = obb.mesh_aabb().maximums() - obb.mesh_aabb().minimums();
+            diff.x * diff.y * diff.z
+        };
+        candidates
+            .into_iter()
+            .fold(None, |best: Option<(Obb, f32)>, candidate| {
+                let volume = volume_of(&candidate);
+                match best {
+                    Some(best) if best.1 <= volume => Some(best),
+                    _ => Some((candidate, volume)),
+                }
+            })
+            .map(|(obb, _)| obb)
+            .unwrap()
+    }
     /// Given a list of mesh vertices, and the orientation of this mesh, constructs an oriented
     /// bounding box.
     fn compute_obb(vertices: &[Vec3], orientation: Quat) -> Obb {
@@ -104,66 +421,142 @@ impl Obb {
 }
 
 impl BoundingVolume for Obb {
-    fn new(mesh: &Mesh, _transform: &GlobalTransform) -> Self {
-        // Grab a vector of vertex coordinates we can use to iterate through
-        if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
-            panic!("Non-TriangleList mesh supplied for oriented bounding box generation")
-        }
-        let vertices: Vec<Vec3> = match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
-            None => panic!("Mesh does not contain vertex positions"),
-            Some(vertex_values) => match &vertex_values {
-                VertexAttributeValues::Float32x3(positions) => positions
-                    .iter()
-                    .map(|coordinates| Vec3::from(*coordinates))
-                    .collect(),
-                _ => panic!("Unexpected vertex types in ATTRIBUTE_POSITION"),
-            },
-        };
+    fn from_vertices(vertices: &[Vec3], _transform: &GlobalTransform) -> Self {
+        Obb::sweep(vertices, DEFAULT_ANGULAR_STEP, None)
+    }
 
-        let mut orientation = Quat::IDENTITY;
-        let mut volume = f32::MAX;
-        // Rotate about y-axis  (turntable) until the smallest volume box is found
-        let orientation_temp = orientation;
-        for angle in (0..45).step_by(15) {
-            let new_orientation =
-                orientation_temp * Quat::from_rotation_y(angle as f32 * 2.0 * PI / 360.0);
-            let temp_obb = Obb::compute_obb(&vertices, new_orientation);
-            let diff = temp_obb.mesh_aabb().maximums() - temp_obb.mesh_aabb().minimums();
-            let new_volume = diff.x * diff.y * diff.z;
-            if new_volume < volume {
-                volume = new_volume;
-                orientation = new_orientation;
-            }
-        }
-        let mut obb = Obb::compute_obb(&vertices, orientation);
-        let orientation_temp = orientation;
-        for angle in (0..90).step_by(15) {
-            let new_orientation =
-                orientation_temp * Quat::from_rotation_x(angle as f32 * 2.0 * PI / 360.0);
-            let temp_obb = Obb::compute_obb(&vertices, new_orientation);
-            let diff = temp_obb.mesh_aabb().maximums() - temp_obb.mesh_aabb().minimums();
-            let new_volume = diff.x * diff.y * diff.z;
-            if new_volume < volume {
-                volume = new_volume;
-                obb = temp_obb;
-            }
+    /// [FitMethod::Precise] sweeps in steps of 5 degrees, and [FitMethod::Fast] in steps of 30
+    /// degrees, see [Obb::new_with_steps].
+    fn from_vertices_with_method(
+        vertices: &[Vec3],
+        _transform: &GlobalTransform,
+        method: FitMethod,
+    ) -> Self {
+        match method {
+            FitMethod::Precise => Obb::sweep(vertices, PRECISE_ANGULAR_STEP, None),
+            FitMethod::Fast => Obb::sweep(vertices, FAST_ANGULAR_STEP, None),
         }
-        obb
     }
 
-    fn new_debug_mesh(&self, _transform: &GlobalTransform) -> Mesh {
-        Mesh::from(self)
+    fn new_debug_mesh(
+        &self,
+        _transform: &GlobalTransform,
+        options: &DebugMeshOptions,
+    ) -> Result<Mesh, BoundingError> {
+        let obb = self.inflated(options.inflation);
+        Ok(match options.topology {
+            DebugMeshTopology::Lines => Mesh::from(&obb),
+            DebugMeshTopology::Triangles => box_triangle_mesh(&obb.vertices_mesh_space()),
+        })
+    }
+
+    /// Draws the axes of boxes marked with [DebugObbAxes](crate::debug::DebugObbAxes).
+    #[cfg(feature = "debug")]
+    fn register_debug(app: &mut App) {
+        app.add_system_to_stage(
+            CoreStage::PostUpdate,
+            update_debug_obb_axes
+                .system()
+                .with_run_criteria(debug_renderer_present.system())
+                .after(BoundingSystem::UpdateBounds),
+        );
     }
 
     fn update_on_transform_change(
         &self,
-        _mesh: &Mesh,
+        _vertices: &VertexSource,
         _transform: &GlobalTransform,
     ) -> Option<Self> {
         // No-op
         None
     }
 
+    fn world_aabb(&self, bound_vol_position: &GlobalTransform) -> Aabb {
+        Aabb::compute_aabb(&self.vertices(*bound_vol_position))
+    }
+
+    fn project_onto_axis(&self, bound_vol_position: &GlobalTransform, axis: Vec3) -> (f32, f32) {
+        project_vertices(&self.vertices(*bound_vol_position), axis)
+    }
+
+    /// Uses the separating axis test, see [intersect::obb_obb].
+    fn intersects(
+        &self,
+        bound_vol_position: &GlobalTransform,
+        other: &Self,
+        other_position: &GlobalTransform,
+    ) -> bool {
+        intersect::obb_obb(self, bound_vol_position, other, other_position)
+    }
+
+    /// A box can only be baked exactly with a uniform scale; with a non-uniform scale, the box is
+    /// scaled by the largest component of the scale, so it still encloses the mesh.
+    fn to_world_space(&self, bound_vol_position: &GlobalTransform) -> Self {
+        let scale = bound_vol_position.scale.abs().max_element();
+        let orientation = bound_vol_position.rotation * self.orientation();
+        // The translation is moved into the extents, in the frame of the box.
+        let offset = orientation.conjugate() * bound_vol_position.translation;
+        Obb::from_aabb_orientation(
+            Aabb::from_extents(
+                self.aabb.minimums() * scale + offset,
+                self.aabb.maximums() * scale + offset,
+            ),
+            orientation.conjugate(),
+        )
+    }
+
+    fn center(&self, bound_vol_position: &GlobalTransform) -> Vec3 {
+        let mesh_aabb_center = (self.aabb.minimums() + self.aabb.maximums()) * 0.5;
+        bound_vol_position.mul_vec3(self.orientation() * mesh_aabb_center)
+    }
+
+    fn contains_point(&self, bound_vol_position: &GlobalTransform, point: Vec3) -> bool {
+        // Move the point into the space of the mesh-space AABB, where the test is axis aligned.
+        let world_to_box =
+            (bound_vol_position.compute_matrix() * Mat4::from_quat(self.orientation())).inverse();
+        let point = world_to_box.transform_point3(point);
+        self.aabb.minimums().cmple(point).all() && point.cmple(self.aabb.maximums()).all()
+    }
+
+    fn world_volume(&self, bound_vol_position: &GlobalTransform) -> f32 {
+        self.volume(bound_vol_position)
+    }
+
+    fn longest_axis(&self, bound_vol_position: &GlobalTransform) -> (Vec3, f32) {
+        let (axes, half_extents) = self.axes_and_half_extents(bound_vol_position);
+        axes.iter()
+            .zip([half_extents.x, half_extents.y, half_extents.z].iter())
+            .fold(
+                (axes[0], half_extents.x * 2.0),
+                |longest, (&axis, &half_extent)| {
+                    if half_extent * 2.0 > longest.1 {
+                        (axis, half_extent * 2.0)
+                    } else {
+                        longest
+                    }
+                },
+            )
+    }
+
+    fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        // A quaternion and its negation represent the same rotation.
+        self.aabb.approx_eq(&other.aabb, epsilon)
+            && (self
+                .mesh_orientation
+                .abs_diff_eq(other.mesh_orientation, epsilon)
+                || self
+                    .mesh_orientation
+                    .abs_diff_eq(-other.mesh_orientation, epsilon))
+    }
+
+    fn inflated(&self, factor: f32) -> Self {
+        Obb::from_aabb_orientation(self.aabb.inflated(factor), self.mesh_orientation)
+    }
+
+    fn kind(&self) -> BoundingKind {
+        BoundingKind::Obb
+    }
+
     fn outside_plane(
         &self,
         bound_vol_position: &GlobalTransform,
@@ -179,3 +572,9 @@ impl BoundingVolume for Obb {
         true
     }
 }
+
+impl From<&Obb> for Mesh {
+    fn from(obb: &Obb) -> Self {
+        line_list_mesh(&obb.edges_mesh_space())
+    }
+}
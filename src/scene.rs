@@ -0,0 +1,39 @@
+use crate::{aabb::Aabb, BoundingVolume};
+use bevy::prelude::*;
+use std::marker::PhantomData;
+
+/// The world space axis-aligned bounding box of every entity with a bounding volume of type T,
+/// updated every frame. This is useful for framing the whole scene with a camera.
+pub struct SceneBounds<T: BoundingVolume> {
+    total: Option<Aabb>,
+    marker: PhantomData<T>,
+}
+
+impl<T: BoundingVolume> Default for SceneBounds<T> {
+    fn default() -> Self {
+        SceneBounds {
+            total: None,
+            marker: PhantomData::default(),
+        }
+    }
+}
+
+impl<T: BoundingVolume> SceneBounds<T> {
+    /// Returns the world space [Aabb] enclosing all bounding volumes of type T, or `None` if there
+    /// are no bounded entities in the scene. Because the box is in world space, it should be used
+    /// with an identity [GlobalTransform].
+    pub fn total(&self) -> Option<Aabb> {
+        self.total.clone()
+    }
+}
+
+/// Merges the world space AABBs of every bounding volume of type T into the [SceneBounds].
+pub fn update_scene_bounds<T: 'static + BoundingVolume + Send + Sync + Component>(
+    mut scene_bounds: ResMut<SceneBounds<T>>,
+    query: Query<(&T, &GlobalTransform)>,
+) {
+    scene_bounds.total = query
+        .iter()
+        .map(|(bound_vol, transform)| bound_vol.world_aabb(transform))
+        .reduce(|total, aabb| total.merged(&aabb));
+}
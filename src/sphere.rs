@@ -1,9 +1,23 @@
-use crate::BoundingVolume;
+use crate::{
+    aabb::Aabb,
+    bytes::{floats_from_bytes, floats_to_bytes},
+    frustum::Frustum,
+    intersect,
+    mesh::transform_positions,
+    projected_radius,
+    vertex::{finite_vertices, unique_vertices, VertexSource},
+    BoundingError, BoundingKind, BoundingVolume, DebugMeshOptions, DebugMeshTopology, FitMethod,
+    MIN_SCALE,
+};
+#[cfg(feature = "debug")]
+use crate::{
+    debug::{debug_renderer_present, update_debug_sphere_support},
+    BoundingSystem,
+};
 use bevy::{
     prelude::*,
-    render::{mesh::VertexAttributeValues, pipeline::PrimitiveTopology},
+    render::{mesh::Indices, pipeline::PrimitiveTopology},
 };
-use core::panic;
 
 /// Defines a bounding sphere with a radius and an origin at the center.
 #[derive(Debug, Clone, Default, Component)]
@@ -16,16 +30,86 @@ pub struct BSphere {
     mesh_space_radius: f32,
 }
 impl BSphere {
+    /// Creates a bounding sphere from its origin and radius in mesh space.
+    pub fn from_origin_radius(mesh_space_origin: Vec3, mesh_space_radius: f32) -> BSphere {
+        BSphere {
+            mesh_space_origin,
+            mesh_space_radius,
+        }
+    }
     /// Given the current [GlobalTransform] of the bounded mesh, returns the central origin of the
     /// sphere that bounds the mesh in world space.
     pub fn origin(&self, transform: GlobalTransform) -> Vec3 {
-        self.mesh_space_origin + transform.translation
+        transform.mul_vec3(self.mesh_space_origin)
     }
     /// Given the current [GlobalTransform] of the bounded mesh, returns the radius of the sphere
-    /// that bounds the mesh in world space.
+    /// that bounds the mesh in world space. Under a non-uniform scale, the mesh space sphere is
+    /// stretched into an ellipsoid, and this is the radius of the sphere that encloses it.
     pub fn radius(&self, transform: &GlobalTransform) -> f32 {
         self.mesh_space_radius * transform.scale.max_element()
     }
+    /// Returns the volume of the sphere in world space, given the current mesh transform.
+    pub fn volume(&self, transform: &GlobalTransform) -> f32 {
+        4.0 / 3.0 * std::f32::consts::PI * self.radius(transform).powi(3)
+    }
+    /// Creates the bounding sphere around an [Aabb] fit with the same `transform`, with its
+    /// origin at the center of the box and a radius of half of the box diagonal. This avoids
+    /// reading the vertices of the mesh again, but the sphere is looser than a sphere fit to the
+    /// mesh itself.
+    pub fn from_aabb(aabb: &Aabb, transform: &GlobalTransform) -> BSphere {
+        // The extents of the AABB are rotated and scaled, so they are moved back to mesh space.
+        let (start, end) = aabb.mesh_space_diagonal(transform);
+        let radius = (aabb.maximums() - aabb.minimums()).length() * 0.5;
+        BSphere::from_origin_radius(start.lerp(end, 0.5), radius / transform.scale.max_element())
+    }
+    /// Computes a near-minimal sphere enclosing all of the `spheres`, each given with the transform
+    /// of its mesh, such as the children merged into the bounds of their parent. Like Ritter's
+    /// algorithm, the initial sphere spans the two spheres that are farthest apart, and is then
+    /// grown to enclose the rest, which is tighter than merging the spheres pairwise in an
+    /// arbitrary order. The returned sphere is in world space, so it is used with
+    /// [GlobalTransform::identity].
+    pub fn enclosing(spheres: &[(BSphere, GlobalTransform)]) -> BSphere {
+        let world: Vec<(Vec3, f32)> = spheres
+            .iter()
+            .map(|(sphere, transform)| (sphere.origin(*transform), sphere.radius(transform)))
+            .collect();
+        let first = match world.first() {
+            Some(first) => *first,
+            None => return BSphere::from_origin_radius(Vec3::ZERO, 0.0),
+        };
+        // The sphere reaching farthest from `from`, measured to the far side of the sphere.
+        let farthest_from = |from: Vec3| {
+            world.iter().copied().fold(first, |farthest, sphere| {
+                if sphere.0.distance(from) + sphere.1 > farthest.0.distance(from) + farthest.1 {
+                    sphere
+                } else {
+                    farthest
+                }
+            })
+        };
+        let a = farthest_from(first.0);
+        let b = farthest_from(a.0);
+        let (origin, radius) = world.iter().fold(enclose_spheres(a, b), |total, sphere| {
+            enclose_spheres(total, *sphere)
+        });
+        BSphere::from_origin_radius(origin, radius)
+    }
+    /// Returns the sphere as a flat binary blob, for precomputed bounds that are loaded without
+    /// parsing. The layout is the mesh space origin as x, y, z, then the mesh space radius, for 4
+    /// little-endian `f32`s in total.
+    pub fn to_bytes(&self) -> [u8; 16] {
+        floats_to_bytes([
+            self.mesh_space_origin.x,
+            self.mesh_space_origin.y,
+            self.mesh_space_origin.z,
+            self.mesh_space_radius,
+        ])
+    }
+    /// Reads a sphere written by [BSphere::to_bytes]. Fails if `bytes` isn't 16 bytes long.
+    pub fn from_bytes(bytes: &[u8]) -> Result<BSphere, BoundingError> {
+        let [x, y, z, radius]: [f32; 4] = floats_from_bytes(bytes)?;
+        Ok(BSphere::from_origin_radius(Vec3::new(x, y, z), radius))
+    }
     /// Get a reference to the b sphere's mesh space origin.
     pub fn mesh_space_origin(&self) -> &Vec3 {
         &self.mesh_space_origin
@@ -34,26 +118,55 @@ impl BSphere {
     pub fn mesh_space_radius(&self) -> &f32 {
         &self.mesh_space_radius
     }
-}
-
-/// Create a valid boundary sphere from a mesh and globaltransform.
-impl BoundingVolume for BSphere {
-    fn new(mesh: &Mesh, _transform: &GlobalTransform) -> Self {
-        // Grab a vector of vertex coordinates we can use to iterate through
-        if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
-            panic!("Non-TriangleList mesh supplied for bounding sphere generation")
+    /// Returns true iff the line segment from `a` to `b`, in world space, intersects the bounding
+    /// sphere. This includes the case where the segment lies entirely inside the sphere.
+    pub fn segment_intersects(&self, transform: &GlobalTransform, a: Vec3, b: Vec3) -> bool {
+        intersect::segment_sphere(self, transform, a, b)
+    }
+    /// Returns true iff the sphere is not entirely outside of any of the planes of the `frustum`.
+    /// This is the cheapest culling test: the distance of the center to each plane is compared to
+    /// the radius, stopping at the first plane that rejects the sphere. Like [Frustum::contains],
+    /// a sphere near a corner of the frustum may be reported as inside.
+    pub fn in_frustum(&self, transform: &GlobalTransform, frustum: &Frustum) -> bool {
+        let center = self.origin(*transform);
+        let radius = self.radius(transform);
+        frustum
+            .planes()
+            .iter()
+            .all(|(point, normal)| normal.dot(center - *point) <= radius)
+    }
+    /// Returns true iff the sphere intersects the cone with its tip at `apex`, opening along `dir`
+    /// with the given `half_angle` in radians, and capped at `range` along `dir`, such as the
+    /// volume lit by a spotlight. A sphere that contains the apex always intersects the cone.
+    pub fn intersects_cone(
+        &self,
+        transform: &GlobalTransform,
+        apex: Vec3,
+        dir: Vec3,
+        half_angle: f32,
+        range: f32,
+    ) -> bool {
+        intersect::sphere_cone(self, transform, apex, dir, half_angle, range)
+    }
+    /// Fits a bounding sphere to mesh space vertices like [BoundingVolume::from_vertices], and also
+    /// returns the [SphereSupport] points that determined the fit.
+    pub fn from_vertices_with_support(vertices: &[Vec3]) -> (BSphere, SphereSupport) {
+        let vertices = &unique_vertices(&finite_vertices(vertices));
+        if vertices.is_empty() {
+            return (
+                BSphere::from_origin_radius(Vec3::ZERO, 0.0),
+                SphereSupport::default(),
+            );
         }
-        let vertices: Vec<Vec3> = match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
-            None => panic!("Mesh does not contain vertex positions"),
-            Some(vertex_values) => match &vertex_values {
-                VertexAttributeValues::Float32x3(positions) => positions
-                    .iter()
-                    .map(|coordinates| Vec3::from(*coordinates))
-                    .collect(),
-                _ => panic!("Unexpected vertex types in ATTRIBUTE_POSITION"),
-            },
-        };
-        let point_x = vertices[0];
+        // Seed from a canonical point, the lexicographically lowest vertex, so the fit doesn't
+        // depend on the order of the vertices.
+        let point_x = vertices.iter().fold(vertices[0], |acc, x| {
+            if [x.x, x.y, x.z] < [acc.x, acc.y, acc.z] {
+                *x
+            } else {
+                acc
+            }
+        });
         // Find point y, the point furthest from point x
         let point_y = vertices.iter().fold(point_x, |acc, x| {
             if x.distance(point_x) >= acc.distance(point_x) {
@@ -71,9 +184,13 @@ impl BoundingVolume for BSphere {
             }
         });
         // Construct a bounding sphere using these two points as the poles
-        let mut sphere = BSphere {
-            mesh_space_origin: point_y.lerp(point_z, 0.5),
-            mesh_space_radius: point_y.distance(point_z) / 2.0,
+        let mut sphere = BSphere::from_origin_radius(
+            point_y.lerp(point_z, 0.5),
+            point_y.distance(point_z) / 2.0,
+        );
+        let mut support = SphereSupport {
+            poles: (point_y, point_z),
+            last_grown_to: None,
         };
         // Iteratively adjust sphere until it encloses all points
         loop {
@@ -89,45 +206,426 @@ impl BoundingVolume for BSphere {
             let point_dist = point_n.distance(sphere.mesh_space_origin);
             if point_dist > sphere.mesh_space_radius {
                 let radius_new = (sphere.mesh_space_radius + point_dist) / 2.0;
+                // Once the point is only outside by float error, the sphere can no longer grow
+                // toward it, so the remaining overshoot is taken up by the verification below.
+                if radius_new <= sphere.mesh_space_radius {
+                    break;
+                }
                 let lerp_ratio = (point_dist - radius_new) / point_dist;
-                sphere = BSphere {
-                    mesh_space_origin: sphere.mesh_space_origin.lerp(point_n, lerp_ratio),
-                    mesh_space_radius: radius_new,
-                };
+                sphere = BSphere::from_origin_radius(
+                    sphere.mesh_space_origin.lerp(point_n, lerp_ratio),
+                    radius_new,
+                );
+                support.last_grown_to = Some(point_n);
             } else {
-                return sphere;
+                break;
             }
         }
+        // Moving the origin toward a point is subject to float error, which can leave a point
+        // that was enclosed just outside of the sphere.
+        (sphere.grown_to_enclose(vertices), support)
     }
+    /// Returns this sphere with its radius grown about the same origin, just enough to enclose
+    /// every vertex. Fitting ends with this pass, which guarantees that every vertex is enclosed
+    /// despite float error. It can also repair a sphere that was authored by hand, or loaded with
+    /// [BSphere::from_bytes], against the vertices of its mesh.
+    pub fn grown_to_enclose(&self, vertices: &[Vec3]) -> BSphere {
+        let max_distance = vertices
+            .iter()
+            .map(|vertex| vertex.distance(self.mesh_space_origin))
+            .fold(0.0, f32::max);
+        BSphere::from_origin_radius(
+            self.mesh_space_origin,
+            self.mesh_space_radius.max(max_distance),
+        )
+    }
+    /// Builds a line mesh, in mesh space, of the three circles where the sphere intersects the
+    /// planes through its origin aligned with the axes, each drawn with `n_points` segments.
+    fn circles_mesh(&self, n_points: usize) -> Mesh {
+        let radius = self.mesh_space_radius;
+        let origin = self.mesh_space_origin;
+        let circle = |point: &dyn Fn(f32, f32) -> Vec3| -> Vec<[f32; 3]> {
+            (0..n_points)
+                .map(|i| {
+                    let angle = i as f32 * 2.0 * std::f32::consts::PI / (n_points as f32);
+                    let position = origin + point(angle.cos() * radius, angle.sin() * radius);
+                    [position.x, position.y, position.z]
+                })
+                .collect()
+        };
+        let vertices = [
+            circle(&|cos, sin| Vec3::new(0.0, sin, cos)),
+            circle(&|cos, sin| Vec3::new(cos, 0.0, sin)),
+            circle(&|cos, sin| Vec3::new(cos, sin, 0.0)),
+        ]
+        .concat();
+        // Each circle is a loop of lines between consecutive points.
+        let indices: Vec<u32> = (0..3)
+            .flat_map(|circle| {
+                (0..n_points).flat_map(move |i| {
+                    let offset = circle * n_points;
+                    [(offset + i) as u32, (offset + (i + 1) % n_points) as u32]
+                })
+            })
+            .collect();
+        let mut mesh = Mesh::new(PrimitiveTopology::LineList);
+        mesh.set_attribute(Mesh::ATTRIBUTE_POSITION, vertices.clone());
+        mesh.set_attribute(Mesh::ATTRIBUTE_UV_0, vertices.clone());
+        mesh.set_attribute(Mesh::ATTRIBUTE_NORMAL, vertices);
+        mesh.set_indices(Some(Indices::U32(indices)));
+        mesh
+    }
+    /// Create a bounding sphere seeded from the centroid (mean) of the mesh's vertices, which is
+    /// then grown in a single pass until it encloses every vertex.
+    ///
+    /// ## Choosing a heuristic
+    /// [BoundingVolume::new] seeds the sphere from the two most distant vertices it can find, which
+    /// wins for evenly distributed or elongated meshes, where those poles lie on the final sphere.
+    /// This centroid seeded variant wins for meshes with a dense cluster of vertices and a few
+    /// outliers, where the furthest pair is skewed toward the outliers and produces an oversized
+    /// sphere. When in doubt, compute both and keep the one with the smaller radius. The
+    /// `sphere_heuristics` benchmark (`cargo bench --bench sphere_heuristics`) compares the radius
+    /// and fit time of each heuristic across a set of test meshes.
+    pub fn new_centroid<'a>(
+        source: impl Into<VertexSource<'a>>,
+        _transform: &GlobalTransform,
+    ) -> Result<BSphere, BoundingError> {
+        Ok(BSphere::from_vertices_centroid(&source.into().vertices()?))
+    }
+    /// Create a centroid seeded bounding sphere from mesh space vertices, see
+    /// [BSphere::new_centroid].
+    pub fn from_vertices_centroid(vertices: &[Vec3]) -> BSphere {
+        BSphere::from_vertices_centroid_with_support(vertices).0
+    }
+    /// Fits a centroid seeded bounding sphere like [BSphere::from_vertices_centroid], and also
+    /// returns the [SphereSupport] points that determined the fit. Both poles are the centroid.
+    pub fn from_vertices_centroid_with_support(vertices: &[Vec3]) -> (BSphere, SphereSupport) {
+        let vertices = &unique_vertices(&finite_vertices(vertices));
+        if vertices.is_empty() {
+            return (
+                BSphere::from_origin_radius(Vec3::ZERO, 0.0),
+                SphereSupport::default(),
+            );
+        }
+        let centroid = vertices.iter().fold(Vec3::ZERO, |acc, x| acc + *x) / vertices.len() as f32;
+        let mut sphere = BSphere::from_origin_radius(centroid, 0.0);
+        let mut support = SphereSupport {
+            poles: (centroid, centroid),
+            last_grown_to: None,
+        };
+        // Growing the sphere to enclose a point always encloses the previous sphere, so a single
+        // pass is enough to enclose every point.
+        for point_n in vertices.iter() {
+            if sphere.enclose(*point_n) {
+                support.last_grown_to = Some(*point_n);
+            }
+        }
+        (sphere, support)
+    }
+    /// Fits a bounding sphere like [BoundingVolume::from_vertices_with_method], or like
+    /// [BoundingVolume::from_vertices] if there is no `method`, and also returns the
+    /// [SphereSupport] points that determined the fit.
+    pub fn from_vertices_with_method_and_support(
+        vertices: &[Vec3],
+        method: Option<FitMethod>,
+    ) -> (BSphere, SphereSupport) {
+        match method {
+            None => BSphere::from_vertices_with_support(vertices),
+            Some(FitMethod::Precise) => {
+                let centroid = BSphere::from_vertices_centroid_with_support(vertices);
+                let sphere = BSphere::from_vertices_with_support(vertices);
+                if centroid.0.mesh_space_radius < sphere.0.mesh_space_radius {
+                    centroid
+                } else {
+                    sphere
+                }
+            }
+            Some(FitMethod::Fast) => BSphere::from_vertices_centroid_with_support(vertices),
+        }
+    }
+    /// Create the tightest bounding sphere centered at the origin of the mesh, with a radius of the
+    /// distance to the furthest vertex. This is usually larger than the sphere from
+    /// [BoundingVolume::new], which places its center wherever the radius is smallest, but is what
+    /// is wanted when the origin of the mesh is a meaningful pivot, such as for explosion or aggro
+    /// ranges.
+    pub fn origin_centered<'a>(
+        source: impl Into<VertexSource<'a>>,
+        _transform: &GlobalTransform,
+    ) -> Result<BSphere, BoundingError> {
+        Ok(BSphere::from_vertices_origin_centered(
+            &source.into().vertices()?,
+        ))
+    }
+    /// Create an origin centered bounding sphere from mesh space vertices, see
+    /// [BSphere::origin_centered].
+    pub fn from_vertices_origin_centered(vertices: &[Vec3]) -> BSphere {
+        let radius = finite_vertices(vertices)
+            .iter()
+            .fold(0.0f32, |radius, vertex| radius.max(vertex.length()));
+        BSphere::from_origin_radius(Vec3::ZERO, radius)
+    }
+    /// Create a bounding sphere that ignores the outermost `percentile` percent of the mesh's
+    /// vertices, measured by distance from their centroid, before fitting. This gives a much
+    /// tighter sphere for meshes with a few sparse spikes or stray vertices.
+    ///
+    /// ## Note
+    /// The result is no longer a strict bound: the discarded vertices lie outside of the sphere.
+    /// It should only be used where a volume that mostly contains the mesh is acceptable, such as
+    /// for visuals or coarse culling.
+    ///
+    /// Returns [BoundingError::InvalidParameter] if the percentile isn't in the range [0, 100).
+    pub fn new_robust<'a>(
+        source: impl Into<VertexSource<'a>>,
+        transform: &GlobalTransform,
+        percentile: f32,
+    ) -> Result<BSphere, BoundingError> {
+        if !(0.0..100.0).contains(&percentile) {
+            return Err(BoundingError::InvalidParameter(format!(
+                "The percentile of discarded vertices must be in the range [0, 100), got {}",
+                percentile
+            )));
+        }
+        let mut vertices = unique_vertices(&finite_vertices(&source.into().vertices()?));
+        if vertices.is_empty() {
+            return Ok(BSphere::from_origin_radius(Vec3::ZERO, 0.0));
+        }
+        let centroid = vertices.iter().fold(Vec3::ZERO, |acc, x| acc + *x) / vertices.len() as f32;
+        vertices.sort_unstable_by(|a, b| {
+            a.distance_squared(centroid)
+                .partial_cmp(&b.distance_squared(centroid))
+                .unwrap()
+        });
+        let discarded = (vertices.len() as f32 * percentile / 100.0) as usize;
+        vertices.truncate(vertices.len() - discarded);
+        Ok(BSphere::from_vertices(&vertices, transform))
+    }
+    /// If the point is outside of the sphere, grows the sphere just enough to enclose both the
+    /// point and the previous sphere. Returns true iff the sphere was grown.
+    fn enclose(&mut self, point: Vec3) -> bool {
+        let point_dist = point.distance(self.mesh_space_origin);
+        if point_dist > self.mesh_space_radius {
+            let radius_new = (self.mesh_space_radius + point_dist) / 2.0;
+            let lerp_ratio = (point_dist - radius_new) / point_dist;
+            self.mesh_space_origin = self.mesh_space_origin.lerp(point, lerp_ratio);
+            self.mesh_space_radius = radius_new;
+            true
+        } else {
+            false
+        }
+    }
+}
 
-    /// Generate a debug mesh, and apply the inverse transform. Because the debug mesh is a child,
-    /// the transform of the parent will be applied to it. This needs to be negated so the bounding
-    /// circle debug mesh isn't warped.
-    fn new_debug_mesh(&self, transform: &GlobalTransform) -> Mesh {
-        let mut mesh = Mesh::from(self);
-        let inverse_transform = Transform::from_matrix(
-            Mat4::from_scale_rotation_translation(Vec3::ONE, transform.rotation, Vec3::ZERO)
-                .inverse(),
-        );
-        match mesh.attribute_mut(Mesh::ATTRIBUTE_POSITION) {
-            None => panic!("Mesh does not contain vertex positions"),
-            Some(vertex_values) => match vertex_values {
-                VertexAttributeValues::Float32x3(ref mut positions) => {
-                    *positions = positions
-                        .iter()
-                        .map(|coordinates| {
-                            inverse_transform.mul_vec3(Vec3::from(*coordinates)).into()
-                        })
-                        .collect()
+/// The mesh space points that determined the fit of a [BSphere], to diagnose why a sphere is larger
+/// than expected. The sphere is seeded with the two `poles` on its diameter, then grown to enclose
+/// any points outside of it, the last of which is `last_grown_to`. A centroid seeded sphere starts
+/// as a point at the centroid, which is both of its poles, and a sphere derived from an [Aabb] has
+/// the ends of the box diagonal as its poles.
+#[derive(Debug, Clone, Default, PartialEq, Component)]
+pub struct SphereSupport {
+    pub poles: (Vec3, Vec3),
+    pub last_grown_to: Option<Vec3>,
+}
+
+/// Opts an entity out of deriving its [BSphere] from its [Aabb], see
+/// [spawn_from_aabbs](crate::spawn_from_aabbs), so the sphere is fit to the mesh itself.
+#[derive(Debug, Clone, Copy, Default, Component)]
+pub struct ExactSphere;
+
+/// Incrementally builds a [BSphere] from mesh space points, for meshes that are streamed in
+/// chunks, or to use the fit outside of the ECS. This grows the sphere in a single pass, like
+/// [BSphere::new_centroid], but without a centroid to seed it, so the result is looser than the
+/// sphere fit of the complete mesh.
+#[derive(Debug, Clone, Default)]
+pub struct BSphereBuilder {
+    sphere: Option<BSphere>,
+}
+impl BSphereBuilder {
+    /// Grows the sphere to enclose the `points`. Non-finite points are skipped.
+    pub fn push_points(&mut self, points: &[Vec3]) {
+        for point in finite_vertices(points).iter() {
+            match &mut self.sphere {
+                Some(sphere) => {
+                    sphere.enclose(*point);
                 }
-                _ => panic!("Unexpected vertex types in ATTRIBUTE_POSITION"),
-            },
+                None => self.sphere = Some(BSphere::from_origin_radius(*point, 0.0)),
+            }
+        }
+    }
+    /// Returns the sphere enclosing all pushed points, or an empty sphere at the origin if no
+    /// points were pushed.
+    pub fn finish(self) -> BSphere {
+        self.sphere
+            .unwrap_or_else(|| BSphere::from_origin_radius(Vec3::ZERO, 0.0))
+    }
+}
+
+/// Create a valid boundary sphere from a mesh and globaltransform.
+impl BoundingVolume for BSphere {
+    /// Non-finite vertices are skipped; if there are no finite vertices, an empty sphere at the
+    /// origin is returned. Duplicate vertices are removed first, so the fit doesn't depend on how
+    /// the mesh was authored.
+    fn from_vertices(vertices: &[Vec3], _transform: &GlobalTransform) -> Self {
+        BSphere::from_vertices_with_support(vertices).0
+    }
+
+    /// [FitMethod::Precise] fits both the default and the centroid seeded sphere, and keeps the
+    /// smaller one, while [FitMethod::Fast] only fits the centroid seeded sphere, which takes a
+    /// single pass over the vertices, see [BSphere::new_centroid].
+    fn from_vertices_with_method(
+        vertices: &[Vec3],
+        _transform: &GlobalTransform,
+        method: FitMethod,
+    ) -> Self {
+        BSphere::from_vertices_with_method_and_support(vertices, Some(method)).0
+    }
+
+    /// Stores and draws the fit support of spheres marked with
+    /// [DebugSphereSupport](crate::debug::DebugSphereSupport).
+    #[cfg(feature = "debug")]
+    fn register_debug(app: &mut App) {
+        app.add_system_to_stage(
+            CoreStage::PostUpdate,
+            update_debug_sphere_support
+                .system()
+                .with_run_criteria(debug_renderer_present.system())
+                .after(BoundingSystem::UpdateBounds),
+        );
+    }
+
+    const DERIVES_FROM_AABB: bool = true;
+
+    /// The sphere around the box, see [BSphere::from_aabb].
+    fn derive_from_aabb(aabb: &Aabb, transform: &GlobalTransform) -> Option<Self> {
+        Some(BSphere::from_aabb(aabb, transform))
+    }
+
+    /// The debug mesh is in mesh space, like the sphere itself, so it is transformed along with
+    /// the bounded mesh. A non-uniform scale would stretch it into an ellipsoid, so the mesh is
+    /// counter-scaled to stay a sphere of [BSphere::radius] in world space. Solid spheres are drawn
+    /// as an icosphere, with one subdivision per 24 segments of resolution.
+    fn new_debug_mesh(
+        &self,
+        transform: &GlobalTransform,
+        options: &DebugMeshOptions,
+    ) -> Result<Mesh, BoundingError> {
+        let sphere = self.inflated(options.inflation);
+        let origin = sphere.mesh_space_origin;
+        let scale = transform.scale;
+        // A scale with a zero component can't be undone; the sphere is flattened along with it.
+        let correction = if scale.abs().min_element() < MIN_SCALE {
+            Vec3::ONE
+        } else {
+            Vec3::splat(scale.max_element()) / scale
         };
-        mesh
+        let correction = Mat4::from_scale(correction);
+        match options.topology {
+            DebugMeshTopology::Lines => {
+                let mut mesh = sphere.circles_mesh(options.resolution);
+                transform_positions(
+                    &mut mesh,
+                    Mat4::from_translation(origin) * correction * Mat4::from_translation(-origin),
+                )?;
+                Ok(mesh)
+            }
+            DebugMeshTopology::Triangles => {
+                let mut mesh = Mesh::from(shape::Icosphere {
+                    radius: sphere.mesh_space_radius,
+                    subdivisions: (options.resolution / 24).max(1),
+                });
+                transform_positions(&mut mesh, Mat4::from_translation(origin) * correction)?;
+                Ok(mesh)
+            }
+        }
     }
 
-    fn update_on_transform_change(&self, mesh: &Mesh, transform: &GlobalTransform) -> Option<Self> {
-        Some(Self::new(mesh, transform))
+    /// Unlike [Aabb], the sphere doesn't track the rotation and scale it was fit with: it is stored
+    /// in mesh space, and its world space radius is derived from the largest component of the
+    /// current scale, see [BSphere::radius]. It stays a valid bound under any transform, so it is
+    /// never refit for a transform change. Only its debug mesh depends on the scale, and it is
+    /// rebuilt when the scale changes.
+    fn update_on_transform_change(
+        &self,
+        _vertices: &VertexSource,
+        _transform: &GlobalTransform,
+    ) -> Option<Self> {
+        None
+    }
+
+    fn world_aabb(&self, bound_vol_position: &GlobalTransform) -> Aabb {
+        let origin = self.origin(*bound_vol_position);
+        let radius = Vec3::splat(self.radius(bound_vol_position));
+        Aabb::from_extents(origin - radius, origin + radius)
+    }
+
+    fn project_onto_axis(&self, bound_vol_position: &GlobalTransform, axis: Vec3) -> (f32, f32) {
+        let center_projection = self.origin(*bound_vol_position).dot(axis);
+        let radius = self.radius(bound_vol_position) * axis.length();
+        (center_projection - radius, center_projection + radius)
+    }
+
+    fn intersects(
+        &self,
+        bound_vol_position: &GlobalTransform,
+        other: &Self,
+        other_position: &GlobalTransform,
+    ) -> bool {
+        intersect::sphere_sphere(self, bound_vol_position, other, other_position)
+    }
+
+    fn to_world_space(&self, bound_vol_position: &GlobalTransform) -> Self {
+        BSphere::from_origin_radius(
+            self.origin(*bound_vol_position),
+            self.radius(bound_vol_position),
+        )
+    }
+
+    fn center(&self, bound_vol_position: &GlobalTransform) -> Vec3 {
+        self.origin(*bound_vol_position)
+    }
+
+    fn contains_point(&self, bound_vol_position: &GlobalTransform, point: Vec3) -> bool {
+        self.origin(*bound_vol_position).distance(point) <= self.radius(bound_vol_position)
+    }
+
+    fn world_volume(&self, bound_vol_position: &GlobalTransform) -> f32 {
+        self.volume(bound_vol_position)
+    }
+
+    fn longest_axis(&self, bound_vol_position: &GlobalTransform) -> (Vec3, f32) {
+        (Vec3::X, self.radius(bound_vol_position) * 2.0)
+    }
+
+    fn projected_size(
+        &self,
+        bound_vol_position: &GlobalTransform,
+        camera: &GlobalTransform,
+        fov_y: f32,
+        viewport_height: f32,
+    ) -> f32 {
+        projected_radius(
+            self.origin(*bound_vol_position),
+            self.radius(bound_vol_position),
+            camera,
+            fov_y,
+            viewport_height,
+        )
+    }
+
+    fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.mesh_space_origin
+            .abs_diff_eq(other.mesh_space_origin, epsilon)
+            && (self.mesh_space_radius - other.mesh_space_radius).abs() <= epsilon
+    }
+
+    fn inflated(&self, factor: f32) -> Self {
+        BSphere {
+            mesh_space_radius: self.mesh_space_radius * factor,
+            ..self.clone()
+        }
+    }
+
+    fn kind(&self) -> BoundingKind {
+        BoundingKind::Sphere
     }
 
     fn outside_plane(
@@ -136,8 +634,30 @@ impl BoundingVolume for BSphere {
         point: Vec3,
         normal: Vec3,
     ) -> bool {
+        // The signed distance is compared against the radius, so the normal must be unit length
+        // for both to be in the same units.
+        let normal = normal.normalize();
         normal.dot(self.origin(*bound_vol_position)) + -normal.dot(point)
             - self.radius(bound_vol_position)
             > 0.0
     }
 }
+
+impl From<&BSphere> for Mesh {
+    fn from(sphere: &BSphere) -> Self {
+        sphere.circles_mesh(DebugMeshOptions::default().resolution)
+    }
+}
+
+/// Returns the smallest sphere, as an origin and radius, that encloses the spheres `a` and `b`.
+fn enclose_spheres(a: (Vec3, f32), b: (Vec3, f32)) -> (Vec3, f32) {
+    let distance = a.0.distance(b.0);
+    if distance + b.1 <= a.1 {
+        return a;
+    }
+    if distance + a.1 <= b.1 {
+        return b;
+    }
+    let radius = (distance + a.1 + b.1) * 0.5;
+    (a.0 + (b.0 - a.0) / distance * (radius - a.1), radius)
+}
@@ -0,0 +1,552 @@
+use crate::BoundingError;
+use bevy::{
+    asset::HandleId,
+    prelude::*,
+    render::{
+        mesh::{Indices, VertexAttributeValues},
+        pipeline::PrimitiveTopology,
+    },
+    utils::{HashMap, HashSet},
+};
+use std::{borrow::Cow, ops::Range, sync::Arc};
+
+/// Grab a vector of vertex coordinates from a [Mesh] that we can use to iterate through. The
+/// positions are converted with the default [IntoPositions]; use a [VertexSource] with a
+/// [PositionDecoder] for other position formats.
+pub fn mesh_vertices(mesh: &Mesh) -> Result<Vec<Vec3>, BoundingError> {
+    VertexSource::new(mesh).vertices()
+}
+
+/// Grab the vertex coordinates of the bulk of a [Mesh], discarding thin protruding details such as
+/// antennae or flagpoles. The faces of the mesh are filtered in two passes over the index buffer:
+/// - Triangles with an area below `min_area_ratio` times the mean triangle area are discarded, so
+///   the tips of details made of small triangles are discarded, while vertices shared with any
+///   larger triangle are kept.
+/// - The remaining triangles are grouped into the connected parts of the mesh, joined by shared
+///   vertex positions, and parts with a total area below `min_area_ratio` times the area of the
+///   largest part are discarded. This catches separate details made of few large but thin
+///   triangles, which the first pass keeps.
+///
+/// Vertices that aren't part of any kept triangle are discarded too.
+///
+/// Only triangle lists have faces to filter; the vertices of meshes with any other topology, or of
+/// meshes where every triangle would be discarded, are returned unfiltered. So are proxy points and
+/// vertex ranges of the source, which the faces of the mesh don't index.
+///
+/// Returns [BoundingError::InvalidParameter] if `min_area_ratio` is negative or NaN.
+pub fn bulk_vertices<'a>(
+    source: impl Into<VertexSource<'a>>,
+    min_area_ratio: f32,
+) -> Result<Vec<Vec3>, BoundingError> {
+    if min_area_ratio.is_nan() || min_area_ratio < 0.0 {
+        return Err(BoundingError::InvalidParameter(format!(
+            "The minimum area ratio must not be negative, got {}",
+            min_area_ratio
+        )));
+    }
+    let source = source.into();
+    let vertices = source.vertices()?;
+    let mesh = source.mesh;
+    if source.proxy.is_some()
+        || source.range.is_some()
+        || mesh.primitive_topology() != PrimitiveTopology::TriangleList
+    {
+        return Ok(vertices);
+    }
+    let indices: Vec<usize> = match mesh.indices() {
+        Some(Indices::U16(indices)) => indices.iter().map(|&index| index as usize).collect(),
+        Some(Indices::U32(indices)) => indices.iter().map(|&index| index as usize).collect(),
+        None => (0..vertices.len()).collect(),
+    };
+    let triangles: Vec<([usize; 3], f32)> = indices
+        .chunks_exact(3)
+        .filter(|triangle| triangle.iter().all(|&index| index < vertices.len()))
+        .map(|triangle| {
+            let [a, b, c] = [
+                vertices[triangle[0]],
+                vertices[triangle[1]],
+                vertices[triangle[2]],
+            ];
+            let area = (b - a).cross(c - a).length() * 0.5;
+            ([triangle[0], triangle[1], triangle[2]], area)
+        })
+        .collect();
+    if triangles.is_empty() {
+        return Ok(vertices);
+    }
+    let mean_area = triangles.iter().map(|(_, area)| area).sum::<f32>() / triangles.len() as f32;
+    let large: Vec<&([usize; 3], f32)> = triangles
+        .iter()
+        .filter(|(_, area)| *area >= mean_area * min_area_ratio)
+        .collect();
+
+    // Meshes duplicate vertices along seams of normals or UVs, so the parts are joined by vertex
+    // position rather than by index.
+    let mut welded: HashMap<[u32; 3], usize> = HashMap::default();
+    let mut parents: Vec<usize> = vertices
+        .iter()
+        .enumerate()
+        .map(|(index, vertex)| {
+            let bits = [vertex.x.to_bits(), vertex.y.to_bits(), vertex.z.to_bits()];
+            *welded.entry(bits).or_insert(index)
+        })
+        .collect();
+    for (triangle, _) in large.iter() {
+        let first = part_root(&mut parents, triangle[0]);
+        for &index in triangle[1..].iter() {
+            let other = part_root(&mut parents, index);
+            parents[other] = first;
+        }
+    }
+    let mut part_areas: HashMap<usize, f32> = HashMap::default();
+    for (triangle, area) in large.iter() {
+        *part_areas
+            .entry(part_root(&mut parents, triangle[0]))
+            .or_default() += area;
+    }
+    let largest_part = part_areas.values().fold(0.0f32, |max, &area| max.max(area));
+
+    let mut kept = vec![false; vertices.len()];
+    for (triangle, _) in large.iter() {
+        if part_areas[&part_root(&mut parents, triangle[0])] >= largest_part * min_area_ratio {
+            for &index in triangle.iter() {
+                kept[index] = true;
+            }
+        }
+    }
+    let bulk: Vec<Vec3> = vertices
+        .iter()
+        .zip(kept.iter())
+        .filter(|(_, &kept)| kept)
+        .map(|(&vertex, _)| vertex)
+        .collect();
+    if bulk.is_empty() {
+        Ok(vertices)
+    } else {
+        Ok(bulk)
+    }
+}
+
+/// Returns the representative vertex of the connected part that `index` belongs to, given the
+/// parent of each vertex in the disjoint set forest of the parts.
+fn part_root(parents: &mut [usize], mut index: usize) -> usize {
+    while parents[index] != index {
+        parents[index] = parents[parents[index]];
+        index = parents[index];
+    }
+    index
+}
+
+/// Grab a vector of vertex coordinates from the given attribute of a [Mesh]. Bounding volumes only
+/// depend on the positions of the vertices, not on how they are connected, so meshes of any
+/// [PrimitiveTopology](bevy::render::pipeline::PrimitiveTopology) are supported, including the
+/// line and point meshes of gizmos and editor geometry.
+pub fn mesh_attribute_vertices(
+    mesh: &Mesh,
+    attribute: impl Into<Cow<'static, str>>,
+) -> Result<Vec<Vec3>, BoundingError> {
+    mesh_attribute_vertices_with(mesh, attribute, &FloatPositions)
+}
+
+/// Grab a vector of vertex coordinates from the given attribute of a [Mesh], converted with a
+/// custom [IntoPositions], such as for packed or normalized integer positions.
+pub fn mesh_attribute_vertices_with(
+    mesh: &Mesh,
+    attribute: impl Into<Cow<'static, str>>,
+    decoder: &dyn IntoPositions,
+) -> Result<Vec<Vec3>, BoundingError> {
+    let attribute = attribute.into();
+    match mesh.attribute(attribute.clone()) {
+        None => Err(BoundingError::MissingAttribute(attribute)),
+        Some(vertex_values) => decoder
+            .into_positions(vertex_values)
+            .ok_or(BoundingError::UnexpectedAttributeType(attribute)),
+    }
+}
+
+/// Converts the values of a mesh attribute into vertex positions. Implement this to bound meshes
+/// with position encodings that aren't read by [FloatPositions], such as packed or normalized
+/// integers, and install it for the bounding volume systems with a [PositionDecoder].
+pub trait IntoPositions: Send + Sync {
+    /// Returns the positions encoded by the `values`, or `None` if their format isn't supported.
+    fn into_positions(&self, values: &VertexAttributeValues) -> Option<Vec<Vec3>>;
+}
+
+/// The default [IntoPositions], which reads `Float32x3` positions, and the x, y, and z components
+/// of `Float32x4` positions.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FloatPositions;
+
+impl IntoPositions for FloatPositions {
+    fn into_positions(&self, values: &VertexAttributeValues) -> Option<Vec<Vec3>> {
+        match values {
+            VertexAttributeValues::Float32x3(positions) => Some(
+                positions
+                    .iter()
+                    .map(|coordinates| Vec3::from(*coordinates))
+                    .collect(),
+            ),
+            VertexAttributeValues::Float32x4(positions) => Some(
+                positions
+                    .iter()
+                    .map(|[x, y, z, _]| Vec3::new(*x, *y, *z))
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// The [IntoPositions] that the bounding volume systems read meshes with. The
+/// [BoundingVolumePlugin](crate::BoundingVolumePlugin) adds [FloatPositions] if this resource isn't
+/// already present, so a custom decoder can be inserted before or after adding the plugin.
+#[derive(Clone)]
+pub struct PositionDecoder(pub Arc<dyn IntoPositions>);
+
+impl PositionDecoder {
+    pub fn new(decoder: impl IntoPositions + 'static) -> Self {
+        PositionDecoder(Arc::new(decoder))
+    }
+}
+
+impl Default for PositionDecoder {
+    fn default() -> Self {
+        PositionDecoder::new(FloatPositions)
+    }
+}
+
+/// Returns only the finite vertices, skipping any NaN or infinite positions, which would otherwise
+/// poison the extents of a bounding volume. A warning is logged with the number of skipped
+/// vertices.
+pub fn finite_vertices(vertices: &[Vec3]) -> Cow<[Vec3]> {
+    let skipped = vertices.iter().filter(|vertex| !vertex.is_finite()).count();
+    if skipped == 0 {
+        Cow::Borrowed(vertices)
+    } else {
+        warn!(
+            "Skipped {} non-finite vertices out of {} while computing bounds",
+            skipped,
+            vertices.len()
+        );
+        Cow::Owned(
+            vertices
+                .iter()
+                .filter(|vertex| vertex.is_finite())
+                .copied()
+                .collect(),
+        )
+    }
+}
+
+/// Returns the vertices with duplicate positions removed. Meshes duplicate vertices along hard
+/// edges and UV seams, which would otherwise weight those positions more heavily in fits that
+/// depend on the distribution of the vertices.
+pub fn unique_vertices(vertices: &[Vec3]) -> Vec<Vec3> {
+    let mut unique = vertices.to_vec();
+    unique.sort_unstable_by_key(|vertex| {
+        [vertex.x.to_bits(), vertex.y.to_bits(), vertex.z.to_bits()]
+    });
+    unique.dedup();
+    unique
+}
+
+/// Selects the [Mesh] attribute that bounding volumes are computed from. This is useful when the
+/// positions to bound, such as bind-pose or LOD positions, are stored in a separate attribute from
+/// the rendered `ATTRIBUTE_POSITION`. Entities without this component use `ATTRIBUTE_POSITION`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Component)]
+pub struct BoundsSourceAttribute(pub Cow<'static, str>);
+
+impl Default for BoundsSourceAttribute {
+    fn default() -> Self {
+        BoundsSourceAttribute(Mesh::ATTRIBUTE_POSITION.into())
+    }
+}
+
+/// Restricts the bounding volumes of an entity to the vertices of its [Mesh] with indices in this
+/// range, so a part of a combined mesh, such as the head of a modular character, can be bounded
+/// without splitting it into a separate mesh. Outside of the ECS, the same result is given by
+/// passing a slice of the vertices to [BoundingVolume::from_vertices](crate::BoundingVolume).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Component)]
+pub struct BoundsVertexRange(pub Range<usize>);
+
+/// Points that the bounding volumes of an entity are computed from instead of its [Mesh]. This is a
+/// lightweight CPU-side proxy, such as the extreme points of the mesh, for meshes whose positions
+/// are only resident on the GPU. When present, the [BoundsSourceAttribute] and [BoundsVertexRange]
+/// of the entity are ignored, but its mesh must still be loaded.
+#[derive(Debug, Clone, PartialEq, Default, Component)]
+pub struct BoundsProxyPoints(pub Vec<Vec3>);
+
+impl BoundsProxyPoints {
+    /// Collects the vertices of the mesh in each of the sampled `poses`, so bounding volumes
+    /// computed from these points enclose the mesh in every pose. With the poses sampled densely
+    /// enough from an animation clip, inserting these points on an animated entity bounds it once,
+    /// conservatively, for the whole clip, instead of refitting it every frame. Without any poses,
+    /// the mesh is bounded at rest.
+    pub fn from_poses<'a>(
+        source: impl Into<VertexSource<'a>>,
+        poses: &[SampledPose],
+    ) -> Result<BoundsProxyPoints, BoundingError> {
+        let rest = source.into().vertices()?;
+        if poses.is_empty() {
+            return Ok(BoundsProxyPoints(rest));
+        }
+        let mut points = Vec::new();
+        for pose in poses {
+            match pose {
+                SampledPose::Transform(transform) => {
+                    points.extend(rest.iter().map(|&vertex| transform.mul_vec3(vertex)))
+                }
+                SampledPose::Vertices(vertices) => points.extend_from_slice(vertices),
+            }
+        }
+        Ok(BoundsProxyPoints(points))
+    }
+}
+
+/// A pose of a mesh sampled from an animation, see [BoundsProxyPoints::from_poses].
+#[derive(Debug, Clone, PartialEq)]
+pub enum SampledPose {
+    /// The whole mesh moved rigidly by this transform, relative to the entity, such as a prop
+    /// animated by its own bone.
+    Transform(Transform),
+    /// The mesh space positions of the deformed vertices, such as a skinned or morphed mesh
+    /// evaluated at a keyframe.
+    Vertices(Vec<Vec3>),
+}
+
+/// The meshes that the bounding volumes of an entity are computed from, instead of its own
+/// `Handle<Mesh>`, such as the meshes of a LOD group or of a multi-material model stored as
+/// separate assets. The vertices of all meshes are bounded together by a single volume, once every
+/// mesh has loaded.
+///
+/// ## Note
+/// The volume is refit when the list of meshes changes, or any of the meshes is modified, and is
+/// updated for a change of the [GlobalTransform] of the entity like any other volume. A
+/// `Handle<Mesh>` on the same entity is only rendered: it never affects the bounds.
+#[derive(Debug, Clone, PartialEq, Default, Component)]
+pub struct BoundsMeshes(pub Vec<Handle<Mesh>>);
+
+impl BoundsMeshes {
+    /// Reads the positions of all meshes, converted with the [PositionDecoder], and concatenates
+    /// them. Returns `None` until every mesh has loaded.
+    pub fn vertices(
+        &self,
+        meshes: &Assets<Mesh>,
+        decoder: &PositionDecoder,
+    ) -> Option<Result<Vec<Vec3>, BoundingError>> {
+        let loaded: Option<Vec<&Mesh>> = self.0.iter().map(|handle| meshes.get(handle)).collect();
+        let vertices: Result<Vec<Vec<Vec3>>, BoundingError> = loaded?
+            .iter()
+            .map(|mesh| {
+                mesh_attribute_vertices_with(mesh, Mesh::ATTRIBUTE_POSITION, decoder.0.as_ref())
+            })
+            .collect();
+        Some(vertices.map(|vertices| vertices.concat()))
+    }
+    /// Returns true if any of the meshes has one of these handles.
+    pub(crate) fn contains_any(&self, handles: &HashSet<HandleId>) -> bool {
+        self.0.iter().any(|handle| handles.contains(&handle.id))
+    }
+}
+
+/// Bounds the entity with the mesh of the given handle instead of its own `Handle<Mesh>`, such as
+/// the most detailed mesh of a LOD group. The bounding volume is then not refit when the rendered
+/// `Handle<Mesh>` of the entity changes, which avoids expensive refits on every LOD transition. The
+/// volume is still updated when the locked mesh itself, or the [GlobalTransform], changes.
+#[derive(Debug, Clone, PartialEq, Component)]
+pub struct LockBoundsToHandle(pub Handle<Mesh>);
+
+impl LockBoundsToHandle {
+    /// Returns the handle of the mesh that an entity is bounded with: the locked handle if there
+    /// is one, or else the entity's own `handle`.
+    pub(crate) fn resolve<'a>(
+        lock: Option<&'a LockBoundsToHandle>,
+        handle: &'a Handle<Mesh>,
+    ) -> &'a Handle<Mesh> {
+        lock.map_or(handle, |lock| &lock.0)
+    }
+}
+
+/// Identifies the vertices read by a [VertexSource] from the mesh with a given handle.
+pub(crate) type VertexSourceKey = (HandleId, Cow<'static, str>, Option<Range<usize>>);
+
+/// The source of the vertices of a bounded entity, which are only read from the mesh when needed.
+pub struct VertexSource<'a> {
+    pub mesh: &'a Mesh,
+    pub attribute: Cow<'static, str>,
+    /// The range of vertex indices to read, or all vertices if `None`.
+    pub range: Option<Range<usize>>,
+    /// Points to use instead of reading the mesh, if any.
+    pub proxy: Option<&'a [Vec3]>,
+    /// Converts the attribute values of the mesh into positions.
+    pub decoder: &'a dyn IntoPositions,
+}
+
+impl<'a> VertexSource<'a> {
+    /// Creates a source reading vertices from the `ATTRIBUTE_POSITION` attribute of the mesh.
+    pub fn new(mesh: &'a Mesh) -> Self {
+        VertexSource {
+            mesh,
+            attribute: Mesh::ATTRIBUTE_POSITION.into(),
+            range: None,
+            proxy: None,
+            decoder: &FloatPositions,
+        }
+    }
+    /// Creates a source reading vertices from the attribute of the mesh selected by the optional
+    /// [BoundsSourceAttribute] of an entity.
+    pub fn with_attribute(mesh: &'a Mesh, attribute: Option<&BoundsSourceAttribute>) -> Self {
+        VertexSource {
+            mesh,
+            attribute: attribute.cloned().unwrap_or_default().0,
+            range: None,
+            proxy: None,
+            decoder: &FloatPositions,
+        }
+    }
+    /// Restricts the source to the vertices selected by the optional [BoundsVertexRange] of an
+    /// entity.
+    pub fn with_range(self, range: Option<&BoundsVertexRange>) -> Self {
+        VertexSource {
+            range: range.map(|range| range.0.clone()),
+            ..self
+        }
+    }
+    /// Makes the source use the optional [BoundsProxyPoints] of an entity instead of the mesh.
+    pub fn with_proxy(self, proxy: Option<&'a BoundsProxyPoints>) -> Self {
+        VertexSource {
+            proxy: proxy.map(|proxy| proxy.0.as_slice()),
+            ..self
+        }
+    }
+    /// Makes the source use these points instead of reading the mesh, such as vertices that were
+    /// already read from it.
+    pub fn with_points(self, points: &'a [Vec3]) -> Self {
+        VertexSource {
+            proxy: Some(points),
+            ..self
+        }
+    }
+    /// Makes the source convert the attribute values of the mesh with the [PositionDecoder].
+    pub fn with_decoder(self, decoder: &'a PositionDecoder) -> Self {
+        VertexSource {
+            decoder: decoder.0.as_ref(),
+            ..self
+        }
+    }
+    /// Reads the vertices from the mesh, or returns the proxy points.
+    pub fn vertices(&self) -> Result<Vec<Vec3>, BoundingError> {
+        if let Some(proxy) = self.proxy {
+            return Ok(proxy.to_vec());
+        }
+        let vertices =
+            mesh_attribute_vertices_with(self.mesh, self.attribute.clone(), self.decoder)?;
+        match &self.range {
+            None => Ok(vertices),
+            Some(range) => match vertices.get(range.clone()) {
+                Some(selected) => Ok(selected.to_vec()),
+                None => Err(BoundingError::VertexRangeOutOfBounds {
+                    range: range.clone(),
+                    vertex_count: vertices.len(),
+                }),
+            },
+        }
+    }
+    /// Returns the key of the vertices in the mesh with this handle, or `None` for proxy points,
+    /// which belong to an entity rather than a mesh.
+    pub(crate) fn key(&self, handle: &Handle<Mesh>) -> Option<VertexSourceKey> {
+        match self.proxy {
+            Some(_) => None,
+            None => Some((handle.id, self.attribute.clone(), self.range.clone())),
+        }
+    }
+}
+
+impl<'a> From<&'a Mesh> for VertexSource<'a> {
+    fn from(mesh: &'a Mesh) -> Self {
+        VertexSource::new(mesh)
+    }
+}
+
+/// Caches the vertex positions read from meshes, keyed by mesh handle, attribute, and range, so
+/// that entities with several bounding volume types only read and convert each mesh attribute once.
+/// The cache only lives for the duration of the `spawn` systems, and is cleared every frame by
+/// [clear_vertex_cache], so it can never be stale.
+#[derive(Debug, Default)]
+pub struct MeshVertexCache {
+    vertices: HashMap<VertexSourceKey, Vec<Vec3>>,
+}
+impl MeshVertexCache {
+    /// Returns the cached vertices of the source, reading them from the mesh with this handle if
+    /// they are not cached. Proxy points are returned as is. Vertices that can't be read are not
+    /// cached.
+    pub fn get_or_insert<'s>(
+        &'s mut self,
+        handle: &Handle<Mesh>,
+        source: &'s VertexSource,
+    ) -> Result<&'s [Vec3], BoundingError> {
+        match source.key(handle) {
+            Some(key) => {
+                if !self.vertices.contains_key(&key) {
+                    let vertices = source.vertices()?;
+                    self.vertices.insert(key.clone(), vertices);
+                }
+                Ok(&self.vertices[&key])
+            }
+            None => Ok(source.proxy.unwrap_or_default()),
+        }
+    }
+    pub fn clear(&mut self) {
+        self.vertices.clear();
+    }
+    /// Removes the cached vertices of the meshes with these handles.
+    pub fn invalidate(&mut self, meshes: &HashSet<HandleId>) {
+        self.vertices.retain(|key, _| !meshes.contains(&key.0));
+    }
+}
+
+/// Empties the [MeshVertexCache] once all bounding volumes have been spawned for this frame.
+pub fn clear_vertex_cache(mut cache: ResMut<MeshVertexCache>) {
+    cache.clear();
+}
+
+/// Caches the vertex positions of meshes instanced with [SharedBounds](crate::SharedBounds). Unlike
+/// the [MeshVertexCache], entries are kept across frames, so instances spawned later, and instances
+/// refit for a rotation or scale change, don't read the mesh again. Entries are removed by
+/// [invalidate_shared_vertices] when their mesh is modified or removed.
+#[derive(Debug, Default)]
+pub struct SharedVertexCache {
+    cache: MeshVertexCache,
+}
+impl SharedVertexCache {
+    /// Returns the cached vertices of the source, see [MeshVertexCache::get_or_insert].
+    pub fn get_or_insert<'s>(
+        &'s mut self,
+        handle: &Handle<Mesh>,
+        source: &'s VertexSource,
+    ) -> Result<&'s [Vec3], BoundingError> {
+        self.cache.get_or_insert(handle, source)
+    }
+}
+
+/// Returns the meshes that were modified or removed, whose cached vertices or bounds are stale.
+pub(crate) fn stale_meshes(events: &mut EventReader<AssetEvent<Mesh>>) -> HashSet<HandleId> {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            AssetEvent::Modified { handle } | AssetEvent::Removed { handle } => Some(handle.id),
+            AssetEvent::Created { .. } => None,
+        })
+        .collect()
+}
+
+/// Removes the vertices of modified or removed meshes from the [SharedVertexCache].
+pub fn invalidate_shared_vertices(
+    mut events: EventReader<AssetEvent<Mesh>>,
+    mut cache: ResMut<SharedVertexCache>,
+) {
+    let stale = stale_meshes(&mut events);
+    if !stale.is_empty() {
+        cache.cache.invalidate(&stale);
+    }
+}
@@ -0,0 +1,62 @@
+use bevy::{
+    prelude::*,
+    render::{
+        mesh::{Indices, VertexAttributeValues},
+        pipeline::PrimitiveTopology,
+    },
+};
+use bevy_mod_bounding::{aabb::Aabb, BoundingError, BoundingVolume};
+
+/// A cube of size 2 with a flagpole standing on its top face. The pole is a separate part made of
+/// two long, thin triangles, which are each too large to be discarded by their area alone.
+fn cube_with_flagpole() -> Mesh {
+    let cube = Mesh::from(shape::Cube { size: 2.0 });
+    let mut positions = match cube.attribute(Mesh::ATTRIBUTE_POSITION) {
+        Some(VertexAttributeValues::Float32x3(positions)) => positions.clone(),
+        _ => unreachable!("cubes have float positions"),
+    };
+    let mut indices = match cube.indices() {
+        Some(Indices::U32(indices)) => indices.clone(),
+        _ => unreachable!("cubes have u32 indices"),
+    };
+    let base = positions.len() as u32;
+    positions.extend_from_slice(&[
+        [0.0, 1.0, 0.0],
+        [0.2, 1.0, 0.0],
+        [0.2, 5.0, 0.0],
+        [0.0, 5.0, 0.0],
+    ]);
+    indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+    mesh.set_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.set_indices(Some(Indices::U32(indices)));
+    mesh
+}
+
+#[test]
+fn bulk_bound_ignores_a_flagpole() {
+    let mesh = cube_with_flagpole();
+    let transform = GlobalTransform::identity();
+    let cube = Aabb::from_extents(Vec3::splat(-1.0), Vec3::splat(1.0));
+
+    let strict = Aabb::new(&mesh, &transform).unwrap();
+    assert_eq!(strict.maximums().y, 5.0);
+    let bulk = Aabb::new_bulk(&mesh, &transform, 0.1).unwrap();
+    assert!(bulk.approx_eq(&cube, 1e-6));
+    // Without a threshold, nothing is discarded.
+    let kept = Aabb::new_bulk(&mesh, &transform, 0.0).unwrap();
+    assert!(kept.approx_eq(&strict, 1e-6));
+}
+
+#[test]
+fn bulk_bound_rejects_invalid_ratios() {
+    let mesh = cube_with_flagpole();
+    let transform = GlobalTransform::identity();
+    for &ratio in [-0.1, f32::NAN].iter() {
+        assert!(matches!(
+            Aabb::new_bulk(&mesh, &transform, ratio),
+            Err(BoundingError::InvalidParameter(_))
+        ));
+    }
+}
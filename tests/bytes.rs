@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+use bevy_mod_bounding::{aabb::Aabb, obb::Obb, sphere::BSphere, BoundingError};
+
+#[test]
+fn aabb_round_trip() {
+    let aabb = Aabb::from_extents(Vec3::new(-1.0, -2.5, 0.25), Vec3::new(3.0, 0.5, 7.75));
+    let read = Aabb::from_bytes(&aabb.to_bytes()).unwrap();
+    assert_eq!(read.minimums(), aabb.minimums());
+    assert_eq!(read.maximums(), aabb.maximums());
+}
+
+#[test]
+fn obb_round_trip() {
+    let obb = Obb::from_aabb_orientation(
+        Aabb::from_extents(Vec3::new(-2.0, -1.0, -0.5), Vec3::new(2.0, 1.0, 0.5)),
+        Quat::from_rotation_y(0.7) * Quat::from_rotation_x(-0.2),
+    );
+    let read = Obb::from_bytes(&obb.to_bytes()).unwrap();
+    assert_eq!(read.mesh_aabb().minimums(), obb.mesh_aabb().minimums());
+    assert_eq!(read.mesh_aabb().maximums(), obb.mesh_aabb().maximums());
+    assert_eq!(read.orientation(), obb.orientation());
+}
+
+#[test]
+fn sphere_round_trip() {
+    let sphere = BSphere::from_origin_radius(Vec3::new(0.5, -3.0, 12.0), 4.25);
+    let read = BSphere::from_bytes(&sphere.to_bytes()).unwrap();
+    assert_eq!(read.mesh_space_origin(), sphere.mesh_space_origin());
+    assert_eq!(read.mesh_space_radius(), sphere.mesh_space_radius());
+}
+
+#[test]
+fn wrong_length_is_rejected() {
+    let bytes = [0u8; 44];
+    let error = |expected, found| BoundingError::InvalidByteLength { expected, found };
+    assert_eq!(Aabb::from_bytes(&bytes[..23]).unwrap_err(), error(24, 23));
+    assert_eq!(Aabb::from_bytes(&bytes[..28]).unwrap_err(), error(24, 28));
+    assert_eq!(Obb::from_bytes(&bytes[..36]).unwrap_err(), error(40, 36));
+    assert_eq!(Obb::from_bytes(&bytes).unwrap_err(), error(40, 44));
+    assert_eq!(BSphere::from_bytes(&bytes[..0]).unwrap_err(), error(16, 0));
+    assert_eq!(
+        BSphere::from_bytes(&bytes[..24]).unwrap_err(),
+        error(16, 24)
+    );
+}
@@ -0,0 +1,35 @@
+//! Fixtures shared by the integration tests. Each test file only uses some of them.
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+
+/// The corners of a box centered on the origin with the given half-extents, rotated by `rotation`.
+pub fn box_corners(half_extents: Vec3, rotation: Quat) -> Vec<Vec3> {
+    let mut corners = Vec::new();
+    for &x in [-1.0, 1.0].iter() {
+        for &y in [-1.0, 1.0].iter() {
+            for &z in [-1.0, 1.0].iter() {
+                corners.push(rotation * (half_extents * Vec3::new(x, y, z)));
+            }
+        }
+    }
+    corners
+}
+
+/// An app with just enough plugins for the bounding volume systems, without a renderer. Bounding
+/// volume plugins are added by each test.
+pub fn headless_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(bevy::asset::AssetPlugin)
+        .add_asset::<Mesh>();
+    app
+}
+
+/// A mesh with only these vertex positions.
+pub fn points_mesh(points: &[Vec3]) -> Mesh {
+    let mut mesh = Mesh::new(bevy::render::pipeline::PrimitiveTopology::PointList);
+    let positions: Vec<[f32; 3]> = points.iter().map(|point| point.to_array()).collect();
+    mesh.set_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh
+}
@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use bevy_mod_bounding::{aabb::Aabb, compute_bounds_now, Bounded, BoundingError};
+
+#[test]
+fn computing_bounds_without_meshes_is_an_error() {
+    let mut world = World::new();
+    let entity = world
+        .spawn()
+        .insert_bundle((
+            Handle::<Mesh>::default(),
+            GlobalTransform::identity(),
+            Bounded::<Aabb>::default(),
+        ))
+        .id();
+    assert_eq!(
+        compute_bounds_now::<Aabb>(&mut world),
+        Err(BoundingError::MissingResource("Assets<Mesh>"))
+    );
+    assert!(world.get::<Bounded<Aabb>>(entity).is_some());
+}
@@ -0,0 +1,74 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_mod_bounding::{obb::Obb, sphere::BSphere, BoundingVolume};
+use common::box_corners;
+
+/// The vertices in several different orders: reversed, rotated, and interleaved.
+fn permutations(vertices: &[Vec3]) -> Vec<Vec<Vec3>> {
+    let reversed = vertices.iter().rev().copied().collect();
+    let mut rotated = vertices.to_vec();
+    rotated.rotate_left(3);
+    let interleaved = vertices
+        .iter()
+        .step_by(2)
+        .chain(vertices.iter().skip(1).step_by(2))
+        .copied()
+        .collect();
+    vec![reversed, rotated, interleaved]
+}
+
+fn assert_order_independent(name: &str, vertices: &[Vec3]) {
+    let transform = GlobalTransform::identity();
+    let obb = Obb::from_vertices(vertices, &transform);
+    let sphere = BSphere::from_vertices(vertices, &transform);
+    for permuted in permutations(vertices) {
+        let permuted_obb = Obb::from_vertices(&permuted, &transform);
+        assert_eq!(permuted_obb.orientation(), obb.orientation(), "{}", name);
+        assert_eq!(
+            permuted_obb.mesh_aabb().minimums(),
+            obb.mesh_aabb().minimums(),
+            "{}",
+            name
+        );
+        assert_eq!(
+            permuted_obb.mesh_aabb().maximums(),
+            obb.mesh_aabb().maximums(),
+            "{}",
+            name
+        );
+        let permuted_sphere = BSphere::from_vertices(&permuted, &transform);
+        assert_eq!(
+            permuted_sphere.mesh_space_origin(),
+            sphere.mesh_space_origin(),
+            "{}",
+            name
+        );
+        assert_eq!(
+            permuted_sphere.mesh_space_radius(),
+            sphere.mesh_space_radius(),
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn cube_fit_is_order_independent() {
+    // Every orientation of the sweep that is a multiple of 90 degrees ties for the smallest box.
+    assert_order_independent("cube", &box_corners(Vec3::ONE, Quat::IDENTITY));
+}
+
+#[test]
+fn irregular_fit_is_order_independent() {
+    let mut vertices = box_corners(
+        Vec3::new(2.0, 1.0, 0.5),
+        Quat::from_rotation_y(-30f32.to_radians()),
+    );
+    vertices.extend_from_slice(&[
+        Vec3::new(0.3, 1.4, -0.2),
+        Vec3::new(-1.1, -0.6, 0.9),
+        Vec3::new(2.2, 0.1, 0.4),
+    ]);
+    assert_order_independent("irregular", &vertices);
+}
@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+use bevy_mod_bounding::{aabb::Aabb, frustum::Frustum, CullResult};
+
+/// The camera is moved and turned away from the origin, so the planes aren't axis aligned.
+fn camera() -> GlobalTransform {
+    GlobalTransform {
+        translation: Vec3::new(1.0, 2.0, 3.0),
+        rotation: Quat::from_rotation_y(0.3),
+        scale: Vec3::ONE,
+    }
+}
+
+/// Asserts that a small box is visible just inside of each plane, and culled by that plane just
+/// outside of it. Each plane is given as a point on the face of the frustum and its outward
+/// normal, in camera space, in the order left, right, bottom, top, near, far.
+fn assert_planes(frustum: &Frustum, faces: [(Vec3, Vec3); 6]) {
+    let camera = camera();
+    // The box reaches at most 0.1 * sqrt(3) along any normal, so this clears the plane.
+    let offset = 0.25;
+    let aabb = Aabb::from_extents(Vec3::splat(-0.1), Vec3::splat(0.1));
+    for (plane_index, (point, normal)) in faces.iter().enumerate() {
+        let point = camera.mul_vec3(*point);
+        let normal = camera.rotation * normal.normalize();
+        let inside = GlobalTransform::from_translation(point - normal * offset);
+        let outside = GlobalTransform::from_translation(point + normal * offset);
+        assert_eq!(
+            frustum.classify(&aabb, &inside),
+            CullResult::Visible,
+            "just inside of plane {}",
+            plane_index
+        );
+        assert_eq!(
+            frustum.classify(&aabb, &outside),
+            CullResult::Culled { plane_index },
+            "just outside of plane {}",
+            plane_index
+        );
+    }
+}
+
+#[test]
+fn perspective_planes() {
+    // A 90 degree field of view with a square aspect ratio puts the side planes at 45 degrees.
+    let frustum = Frustum::from_perspective(&camera(), 90f32.to_radians(), 1.0, 1.0, 10.0);
+    assert_planes(
+        &frustum,
+        [
+            (Vec3::new(-5.0, 0.0, -5.0), Vec3::new(-1.0, 0.0, 1.0)),
+            (Vec3::new(5.0, 0.0, -5.0), Vec3::new(1.0, 0.0, 1.0)),
+            (Vec3::new(0.0, -5.0, -5.0), Vec3::new(0.0, -1.0, 1.0)),
+            (Vec3::new(0.0, 5.0, -5.0), Vec3::new(0.0, 1.0, 1.0)),
+            (Vec3::new(0.0, 0.0, -1.0), Vec3::Z),
+            (Vec3::new(0.0, 0.0, -10.0), -Vec3::Z),
+        ],
+    );
+}
+
+#[test]
+fn orthographic_planes() {
+    let frustum = Frustum::from_orthographic(&camera(), -4.0, 4.0, -3.0, 3.0, 1.0, 10.0);
+    assert_planes(
+        &frustum,
+        [
+            (Vec3::new(-4.0, 0.0, -5.0), -Vec3::X),
+            (Vec3::new(4.0, 0.0, -5.0), Vec3::X),
+            (Vec3::new(0.0, -3.0, -5.0), -Vec3::Y),
+            (Vec3::new(0.0, 3.0, -5.0), Vec3::Y),
+            (Vec3::new(0.0, 0.0, -1.0), Vec3::Z),
+            (Vec3::new(0.0, 0.0, -10.0), -Vec3::Z),
+        ],
+    );
+}
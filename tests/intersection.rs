@@ -0,0 +1,221 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_mod_bounding::{aabb::Aabb, intersect, obb::Obb, sphere::BSphere, BoundingVolume};
+use common::box_corners;
+
+fn at(x: f32) -> GlobalTransform {
+    GlobalTransform::from_translation(Vec3::new(x, 0.0, 0.0))
+}
+
+fn aabb(half_size: f32) -> Aabb {
+    Aabb::from_extents(Vec3::splat(-half_size), Vec3::splat(half_size))
+}
+
+/// An axis aligned cube, which the fit leaves unrotated, so its extents are exact.
+fn obb(half_size: f32) -> Obb {
+    Obb::from_vertices(
+        &box_corners(Vec3::splat(half_size), Quat::IDENTITY),
+        &GlobalTransform::identity(),
+    )
+}
+
+fn sphere(radius: f32) -> BSphere {
+    BSphere::from_origin_radius(Vec3::ZERO, radius)
+}
+
+/// A 4 x 2 x 1 box rotated about the y-axis, within the range of angles the fit can align to.
+fn rotated_obb() -> Obb {
+    let rotation = Quat::from_rotation_y(-30f32.to_radians());
+    Obb::from_vertices(
+        &box_corners(Vec3::new(2.0, 1.0, 0.5), rotation),
+        &GlobalTransform::identity(),
+    )
+}
+
+/// Asserts the results of a unit volume at the origin tested against a unit volume along the
+/// x-axis, at offsets where the volumes overlap, just touch, and are just separated.
+fn assert_unit_pair(name: &str, test: impl Fn(&GlobalTransform, &GlobalTransform) -> bool) {
+    let origin = at(0.0);
+    assert!(test(&origin, &at(1.5)), "{}: overlapping", name);
+    assert!(test(&origin, &at(2.0)), "{}: just touching", name);
+    assert!(!test(&origin, &at(2.01)), "{}: just separated", name);
+    assert!(
+        !test(&origin, &at(-2.01)),
+        "{}: separated on the other side",
+        name
+    );
+}
+
+#[test]
+fn aabb_aabb() {
+    assert_unit_pair("aabb-aabb", |a, b| aabb(1.0).intersects(a, &aabb(1.0), b));
+    assert!(aabb(1.0).intersects(&at(0.0), &aabb(0.25), &at(0.5)));
+    assert!(aabb(0.25).intersects(&at(0.5), &aabb(1.0), &at(0.0)));
+}
+
+#[test]
+fn sphere_sphere() {
+    assert_unit_pair("sphere-sphere", |a, b| {
+        sphere(1.0).intersects(a, &sphere(1.0), b)
+    });
+    assert!(sphere(1.0).intersects(&at(0.0), &sphere(0.25), &at(0.5)));
+    assert!(sphere(0.25).intersects(&at(0.5), &sphere(1.0), &at(0.0)));
+}
+
+#[test]
+fn obb_obb() {
+    assert_unit_pair("obb-obb", |a, b| obb(1.0).intersects(a, &obb(1.0), b));
+    assert!(obb(1.0).intersects(&at(0.0), &obb(0.25), &at(0.5)));
+    assert!(obb(0.25).intersects(&at(0.5), &obb(1.0), &at(0.0)));
+}
+
+#[test]
+fn rotated_obb_obb() {
+    // The far end of the long axis of the rotated box is 2 from its center, and the small cube
+    // reaches about 0.34 toward it.
+    let tip = Quat::from_rotation_y(-30f32.to_radians()) * Vec3::X * 2.0;
+    let near = GlobalTransform::from_translation(tip * 1.1);
+    let far = GlobalTransform::from_translation(tip * 1.3);
+    let origin = GlobalTransform::identity();
+    assert!(rotated_obb().intersects(&origin, &obb(0.25), &near));
+    assert!(!rotated_obb().intersects(&origin, &obb(0.25), &far));
+    assert!(rotated_obb().intersects(&origin, &rotated_obb(), &at(1.0)));
+}
+
+#[test]
+fn tiny_rotated_obb_obb() {
+    // The same pair as above, scaled down so the edge cross products are far shorter than
+    // f32::EPSILON, which must not make every separating axis look degenerate.
+    let scale = 1e-3;
+    let tiny = |translation: Vec3| GlobalTransform {
+        translation: translation * scale,
+        rotation: Quat::IDENTITY,
+        scale: Vec3::splat(scale),
+    };
+    let tip = Quat::from_rotation_y(-30f32.to_radians()) * Vec3::X * 2.0;
+    let origin = tiny(Vec3::ZERO);
+    assert!(rotated_obb().intersects(&origin, &obb(0.25), &tiny(tip * 1.1)));
+    assert!(!rotated_obb().intersects(&origin, &obb(0.25), &tiny(tip * 1.3)));
+}
+
+#[test]
+fn aabb_sphere() {
+    assert_unit_pair("aabb-sphere", |a, b| {
+        intersect::aabb_sphere(&aabb(1.0), a, &sphere(1.0), b)
+    });
+    assert!(intersect::aabb_sphere(
+        &aabb(1.0),
+        &at(0.0),
+        &sphere(0.25),
+        &at(0.5)
+    ));
+    assert!(intersect::aabb_sphere(
+        &aabb(0.25),
+        &at(0.5),
+        &sphere(1.0),
+        &at(0.0)
+    ));
+    // Near a corner, the bounds of the sphere overlap the box, but the sphere itself doesn't.
+    let corner = GlobalTransform::from_translation(Vec3::new(1.5, 1.5, 0.0));
+    assert!(aabb(1.0).intersects(&at(0.0), &aabb(0.6), &corner));
+    assert!(!intersect::aabb_sphere(
+        &aabb(1.0),
+        &at(0.0),
+        &sphere(0.6),
+        &corner
+    ));
+}
+
+#[test]
+fn obb_sphere() {
+    assert_unit_pair("obb-sphere", |a, b| {
+        intersect::obb_sphere(&obb(1.0), a, &sphere(1.0), b)
+    });
+    assert!(intersect::obb_sphere(
+        &obb(1.0),
+        &at(0.0),
+        &sphere(0.25),
+        &at(0.5)
+    ));
+    assert!(intersect::obb_sphere(
+        &obb(0.25),
+        &at(0.5),
+        &sphere(1.0),
+        &at(0.0)
+    ));
+    let corner = GlobalTransform::from_translation(Vec3::new(1.5, 1.5, 0.0));
+    assert!(!intersect::obb_sphere(
+        &obb(1.0),
+        &at(0.0),
+        &sphere(0.6),
+        &corner
+    ));
+}
+
+#[test]
+fn rotated_obb_sphere() {
+    let rotation = Quat::from_rotation_y(-30f32.to_radians());
+    let origin = GlobalTransform::identity();
+    // Along the long axis, the box reaches 2 from its center.
+    let along = |distance: f32| GlobalTransform::from_translation(rotation * Vec3::X * distance);
+    assert!(intersect::obb_sphere(
+        &rotated_obb(),
+        &origin,
+        &sphere(0.5),
+        &along(2.4)
+    ));
+    assert!(!intersect::obb_sphere(
+        &rotated_obb(),
+        &origin,
+        &sphere(0.5),
+        &along(2.6)
+    ));
+    // Along the short axis, the box only reaches 0.5 from its center, although its world space
+    // AABB reaches much further.
+    let beside = GlobalTransform::from_translation(rotation * Vec3::Z * 1.2);
+    let world_aabb = rotated_obb().world_aabb(&origin);
+    assert!(intersect::aabb_sphere(
+        &world_aabb,
+        &origin,
+        &sphere(0.5),
+        &beside
+    ));
+    assert!(!intersect::obb_sphere(
+        &rotated_obb(),
+        &origin,
+        &sphere(0.5),
+        &beside
+    ));
+}
+
+#[test]
+fn aabb_obb() {
+    assert_unit_pair("aabb-obb", |a, b| {
+        intersect::aabb_obb(&aabb(1.0), a, &obb(1.0), b)
+    });
+    assert!(intersect::aabb_obb(
+        &aabb(1.0),
+        &at(0.0),
+        &obb(0.25),
+        &at(0.5)
+    ));
+    assert!(intersect::aabb_obb(
+        &aabb(0.25),
+        &at(0.5),
+        &obb(1.0),
+        &at(0.0)
+    ));
+    // Beside the short axis of the rotated box, a small box overlaps its world space AABB but
+    // not the box itself.
+    let rotation = Quat::from_rotation_y(-30f32.to_radians());
+    let beside = GlobalTransform::from_translation(rotation * Vec3::Z * 1.0);
+    let origin = GlobalTransform::identity();
+    assert!(aabb(0.25).intersects(&beside, &rotated_obb().world_aabb(&origin), &origin));
+    assert!(!intersect::aabb_obb(
+        &aabb(0.25),
+        &beside,
+        &rotated_obb(),
+        &origin
+    ));
+}
@@ -0,0 +1,66 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_mod_bounding::{
+    obb::Obb, vertex::LockBoundsToHandle, Bounded, BoundingVolume, BoundingVolumePlugin,
+};
+use common::{box_corners, headless_app, points_mesh};
+
+fn spawn_bounded(app: &mut App, mesh: &Handle<Mesh>) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            mesh.clone(),
+            GlobalTransform::identity(),
+            Bounded::<Obb>::default(),
+        ))
+        .id()
+}
+
+#[test]
+fn modified_mesh_refits_every_entity_sharing_it() {
+    let mut app = headless_app();
+    app.add_plugin(BoundingVolumePlugin::<Obb>::default());
+    let half_extents = Vec3::new(2.0, 1.0, 0.5);
+    let mut meshes = app.world.get_resource_mut::<Assets<Mesh>>().unwrap();
+    let shared = meshes.add(points_mesh(&box_corners(half_extents, Quat::IDENTITY)));
+    let rendered = meshes.add(points_mesh(&box_corners(Vec3::ONE, Quat::IDENTITY)));
+
+    let first = spawn_bounded(&mut app, &shared);
+    let second = spawn_bounded(&mut app, &shared);
+    // Renders another mesh, such as a lower LOD, but is bounded with the shared mesh.
+    let locked = spawn_bounded(&mut app, &rendered);
+    app.world
+        .entity_mut(locked)
+        .insert(LockBoundsToHandle(shared.clone()));
+    app.update();
+    let entities = [first, second, locked];
+    for &entity in entities.iter() {
+        let obb = app.world.get::<Obb>(entity).expect("the entity is bounded");
+        assert_eq!(obb.orientation(), Quat::IDENTITY);
+    }
+
+    // Rotate the box within the mesh, so the fit finds a different orientation.
+    let modified = box_corners(half_extents, Quat::from_rotation_y(-30f32.to_radians()));
+    app.world
+        .get_resource_mut::<Assets<Mesh>>()
+        .unwrap()
+        .get_mut(&shared)
+        .unwrap()
+        .set_attribute(
+            Mesh::ATTRIBUTE_POSITION,
+            modified
+                .iter()
+                .map(|vertex| vertex.to_array())
+                .collect::<Vec<[f32; 3]>>(),
+        );
+    // The modification event is sent at the end of the frame, and read in the next one.
+    app.update();
+    app.update();
+    let expected = Obb::from_vertices(&modified, &GlobalTransform::identity());
+    assert_ne!(expected.orientation(), Quat::IDENTITY);
+    for &entity in entities.iter() {
+        let obb = app.world.get::<Obb>(entity).unwrap();
+        assert!(obb.approx_eq(&expected, 1e-5), "entity {:?}", entity);
+    }
+}
@@ -0,0 +1,97 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_mod_bounding::{
+    aabb::Aabb, vertex::BoundsMeshes, Bounded, BoundingVolume, BoundingVolumePlugin,
+};
+use common::{box_corners, headless_app, points_mesh};
+
+fn add_mesh(app: &mut App, points: &[Vec3]) -> Handle<Mesh> {
+    app.world
+        .get_resource_mut::<Assets<Mesh>>()
+        .unwrap()
+        .add(points_mesh(points))
+}
+
+fn rotate(app: &mut App, entity: Entity, rotation: Quat) {
+    app.world
+        .get_mut::<GlobalTransform>(entity)
+        .unwrap()
+        .rotation = rotation;
+}
+
+fn assert_bounds(app: &App, entity: Entity, vertices: &[Vec3]) {
+    let transform = app.world.get::<GlobalTransform>(entity).unwrap();
+    let expected = Aabb::from_vertices(vertices, transform);
+    let aabb = app
+        .world
+        .get::<Aabb>(entity)
+        .expect("the entity is bounded");
+    assert!(
+        aabb.approx_eq(&expected, 1e-5),
+        "{:?} != {:?}",
+        aabb,
+        expected
+    );
+}
+
+#[test]
+fn rendered_handle_doesnt_replace_the_bounds_meshes() {
+    let mut app = headless_app();
+    app.add_plugin(BoundingVolumePlugin::<Aabb>::default());
+    let bounds_vertices = box_corners(Vec3::new(2.0, 1.0, 0.5), Quat::IDENTITY);
+    let bounds_mesh = add_mesh(&mut app, &bounds_vertices);
+    let rendered = add_mesh(&mut app, &box_corners(Vec3::ONE, Quat::IDENTITY));
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            rendered,
+            BoundsMeshes(vec![bounds_mesh]),
+            GlobalTransform::identity(),
+            Bounded::<Aabb>::default(),
+        ))
+        .id();
+    app.update();
+    assert_bounds(&app, entity, &bounds_vertices);
+
+    // Neither a new rendered mesh nor a rotation refits the volume to the rendered mesh.
+    let lod = add_mesh(&mut app, &box_corners(Vec3::splat(0.5), Quat::IDENTITY));
+    app.world.entity_mut(entity).insert(lod);
+    app.update();
+    assert_bounds(&app, entity, &bounds_vertices);
+    rotate(&mut app, entity, Quat::from_rotation_y(30f32.to_radians()));
+    app.update();
+    assert_bounds(&app, entity, &bounds_vertices);
+}
+
+#[test]
+fn bounds_meshes_refit_on_rotation() {
+    let mut app = headless_app();
+    app.add_plugin(BoundingVolumePlugin::<Aabb>::default());
+    let body = box_corners(Vec3::new(2.0, 1.0, 0.5), Quat::IDENTITY);
+    let wheel = box_corners(Vec3::splat(0.5), Quat::IDENTITY)
+        .iter()
+        .map(|vertex| *vertex + Vec3::new(3.0, 0.0, 0.0))
+        .collect::<Vec<_>>();
+    let meshes = vec![add_mesh(&mut app, &body), add_mesh(&mut app, &wheel)];
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            BoundsMeshes(meshes),
+            GlobalTransform::identity(),
+            Bounded::<Aabb>::default(),
+        ))
+        .id();
+    let vertices = [body, wheel].concat();
+    app.update();
+    assert_bounds(&app, entity, &vertices);
+    let unrotated = app.world.get::<Aabb>(entity).unwrap().clone();
+
+    rotate(&mut app, entity, Quat::from_rotation_y(45f32.to_radians()));
+    app.update();
+    assert_bounds(&app, entity, &vertices);
+    let rotated = app.world.get::<Aabb>(entity).unwrap();
+    assert!(!rotated.approx_eq(&unrotated, 1e-3));
+}
@@ -0,0 +1,100 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_mod_bounding::{obb::Obb, BoundingError, BoundingVolume};
+use common::{box_corners, points_mesh};
+
+const EPSILON: f32 = 1e-4;
+
+/// The corners of a 4 x 2 x 1 box centered on the origin, rotated by `rotation`.
+fn rotated_box_corners(rotation: Quat) -> Vec<Vec3> {
+    box_corners(Vec3::new(2.0, 1.0, 0.5), rotation)
+}
+
+fn assert_same_corners(actual: &[Vec3], expected: &[Vec3]) {
+    for corner in actual.iter() {
+        assert!(
+            expected
+                .iter()
+                .any(|expected| expected.abs_diff_eq(*corner, EPSILON)),
+            "{:?} is not a corner of {:?}",
+            corner,
+            expected
+        );
+    }
+}
+
+#[test]
+fn orientation_maps_the_box_onto_the_mesh() {
+    let mesh_corners = rotated_box_corners(Quat::from_rotation_y(-30f32.to_radians()));
+    let obb = Obb::from_vertices(&mesh_corners, &GlobalTransform::identity());
+    let box_corners: Vec<Vec3> = obb
+        .mesh_aabb()
+        .vertices_mesh_space()
+        .iter()
+        .map(|&corner| obb.orientation() * corner)
+        .collect();
+    assert_same_corners(&box_corners, &mesh_corners);
+    assert_same_corners(&obb.vertices_mesh_space(), &mesh_corners);
+}
+
+#[test]
+fn mesh_to_box_maps_the_mesh_into_the_aabb() {
+    let mesh_corners = rotated_box_corners(Quat::from_rotation_y(-30f32.to_radians()));
+    let obb = Obb::from_vertices(&mesh_corners, &GlobalTransform::identity());
+    let aabb = obb.mesh_aabb();
+    for corner in mesh_corners.iter() {
+        let in_box = obb.mesh_to_box() * *corner;
+        assert!(in_box.cmpge(aabb.minimums() - Vec3::splat(EPSILON)).all());
+        assert!(in_box.cmple(aabb.maximums() + Vec3::splat(EPSILON)).all());
+    }
+    assert!((obb.orientation() * obb.mesh_to_box()).abs_diff_eq(Quat::IDENTITY, EPSILON));
+}
+
+#[test]
+fn world_vertices_follow_the_transform() {
+    let mesh_corners = rotated_box_corners(Quat::from_rotation_y(-30f32.to_radians()));
+    let obb = Obb::from_vertices(&mesh_corners, &GlobalTransform::identity());
+    let transform = GlobalTransform {
+        translation: Vec3::new(1.0, -2.0, 3.0),
+        rotation: Quat::from_rotation_x(0.5),
+        scale: Vec3::ONE,
+    };
+    let world_corners: Vec<Vec3> = mesh_corners
+        .iter()
+        .map(|&corner| transform * corner)
+        .collect();
+    assert_same_corners(&obb.vertices(transform), &world_corners);
+}
+
+#[test]
+fn isometry_rebuilds_the_world_vertices() {
+    let mesh_corners = rotated_box_corners(Quat::from_rotation_y(-30f32.to_radians()));
+    let obb = Obb::from_vertices(&mesh_corners, &GlobalTransform::identity());
+    let transform = GlobalTransform {
+        translation: Vec3::new(-4.0, 1.0, 2.0),
+        rotation: Quat::from_rotation_z(1.2),
+        scale: Vec3::splat(2.0),
+    };
+    let (center, rotation, half_extents) = obb.isometry(&transform);
+    let corners: Vec<Vec3> = rotated_box_corners(Quat::IDENTITY)
+        .iter()
+        // The corners of the canonical box are scaled to the half-extents of the OBB.
+        .map(|&corner| center + rotation * (corner / Vec3::new(2.0, 1.0, 0.5) * half_extents))
+        .collect();
+    assert_same_corners(&corners, &obb.vertices(transform));
+}
+
+#[test]
+fn sweep_rejects_a_step_outside_of_the_sweep_range() {
+    let mesh = points_mesh(&box_corners(Vec3::ONE, Quat::IDENTITY));
+    let transform = GlobalTransform::identity();
+    for &step in [0.0, -15.0, f32::NAN, 1e-30, 0.001, 91.0, f32::INFINITY].iter() {
+        assert!(matches!(
+            Obb::new_with_steps(&mesh, &transform, step),
+            Err(BoundingError::InvalidParameter(_))
+        ));
+    }
+    assert!(Obb::new_with_steps(&mesh, &transform, 15.0).is_ok());
+    assert!(Obb::new_with_steps(&mesh, &transform, 90.0).is_ok());
+}
@@ -0,0 +1,60 @@
+use bevy::{
+    prelude::*,
+    render::{mesh::VertexAttributeValues, pipeline::PrimitiveTopology},
+};
+use bevy_mod_bounding::{
+    aabb::Aabb,
+    obb::Obb,
+    sphere::BSphere,
+    vertex::{IntoPositions, PositionDecoder, VertexSource},
+    BoundingError, BoundingVolume,
+};
+
+/// Decodes positions packed as integers in hundredths of a unit.
+struct Hundredths;
+
+impl IntoPositions for Hundredths {
+    fn into_positions(&self, values: &VertexAttributeValues) -> Option<Vec<Vec3>> {
+        match values {
+            VertexAttributeValues::Sint32x3(positions) => Some(
+                positions
+                    .iter()
+                    .map(|&[x, y, z]| Vec3::new(x as f32, y as f32, z as f32) / 100.0)
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+}
+
+fn packed_mesh() -> Mesh {
+    let mut mesh = Mesh::new(PrimitiveTopology::PointList);
+    mesh.set_attribute(
+        Mesh::ATTRIBUTE_POSITION,
+        VertexAttributeValues::Sint32x3(vec![[-100, -50, -25], [100, 50, 25]]),
+    );
+    mesh
+}
+
+#[test]
+fn constructors_read_packed_positions_with_a_decoder() {
+    let mesh = packed_mesh();
+    let decoder = PositionDecoder::new(Hundredths);
+    let transform = GlobalTransform::identity();
+    assert!(matches!(
+        Aabb::new(&mesh, &transform),
+        Err(BoundingError::UnexpectedAttributeType(_))
+    ));
+
+    let source = || VertexSource::new(&mesh).with_decoder(&decoder);
+    let expected = Aabb::from_extents(Vec3::new(-1.0, -0.5, -0.25), Vec3::new(1.0, 0.5, 0.25));
+    assert!(Aabb::new(source(), &transform)
+        .unwrap()
+        .approx_eq(&expected, 1e-6));
+    assert!(Aabb::new_bulk(source(), &transform, 0.1)
+        .unwrap()
+        .approx_eq(&expected, 1e-6));
+    assert!(Obb::new_with_steps(source(), &transform, 15.0).is_ok());
+    let sphere = BSphere::origin_centered(source(), &transform).unwrap();
+    assert!((*sphere.mesh_space_radius() - Vec3::new(1.0, 0.5, 0.25).length()).abs() < 1e-6);
+}
@@ -0,0 +1,117 @@
+mod common;
+
+use bevy::{prelude::*, render::mesh::VertexAttributeValues};
+use bevy_mod_bounding::{
+    aabb::Aabb,
+    vertex::{FloatPositions, IntoPositions, PositionDecoder},
+    Bounded, BoundingVolume, BoundingVolumePlugin, SharedBounds,
+};
+use common::{box_corners, headless_app, points_mesh};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+/// Counts how many times the positions of a mesh are decoded.
+struct CountingDecoder(Arc<AtomicUsize>);
+
+impl IntoPositions for CountingDecoder {
+    fn into_positions(&self, values: &VertexAttributeValues) -> Option<Vec<Vec3>> {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        FloatPositions.into_positions(values)
+    }
+}
+
+fn spawn_instance(app: &mut App, mesh: &Handle<Mesh>, transform: GlobalTransform) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            mesh.clone(),
+            transform,
+            Bounded::<Aabb>::default(),
+            SharedBounds,
+        ))
+        .id()
+}
+
+fn assert_bounds(app: &App, entity: Entity, vertices: &[Vec3], transform: &GlobalTransform) {
+    let aabb = app
+        .world
+        .get::<Aabb>(entity)
+        .expect("the entity is bounded");
+    assert!(aabb.approx_eq(&Aabb::from_vertices(vertices, transform), 1e-5));
+}
+
+#[test]
+fn instances_in_later_frames_reuse_the_shared_vertices() {
+    let decoded = Arc::new(AtomicUsize::new(0));
+    let mut app = headless_app();
+    app.insert_resource(PositionDecoder::new(CountingDecoder(decoded.clone())))
+        .add_plugin(BoundingVolumePlugin::<Aabb>::default());
+    let vertices = box_corners(Vec3::new(2.0, 1.0, 0.5), Quat::IDENTITY);
+    let mesh = app
+        .world
+        .get_resource_mut::<Assets<Mesh>>()
+        .unwrap()
+        .add(points_mesh(&vertices));
+
+    let identity = GlobalTransform::identity();
+    let rotated = GlobalTransform::from_rotation(Quat::from_rotation_y(0.5));
+    let first = spawn_instance(&mut app, &mesh, identity);
+    app.update();
+    assert_bounds(&app, first, &vertices, &identity);
+    assert_eq!(decoded.load(Ordering::SeqCst), 1);
+
+    // An instance spawned in a later frame, with a different rotation, is bounded from the shared
+    // bound and vertices, without decoding the mesh again.
+    let second = spawn_instance(&mut app, &mesh, rotated);
+    app.update();
+    assert_bounds(&app, second, &vertices, &rotated);
+    assert_eq!(decoded.load(Ordering::SeqCst), 1);
+
+    // Rotating an instance refits it from the shared vertices too.
+    *app.world.get_mut::<GlobalTransform>(first).unwrap() = rotated;
+    app.update();
+    assert_bounds(&app, first, &vertices, &rotated);
+    assert_eq!(decoded.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn translating_an_instance_keeps_its_bounds_in_mesh_space() {
+    let mut app = headless_app();
+    app.add_plugin(BoundingVolumePlugin::<Aabb>::default());
+    let vertices = box_corners(Vec3::new(2.0, 1.0, 0.5), Quat::IDENTITY);
+    let mesh = app
+        .world
+        .get_resource_mut::<Assets<Mesh>>()
+        .unwrap()
+        .add(points_mesh(&vertices));
+
+    let rotated = GlobalTransform::from_rotation(Quat::from_rotation_y(0.5));
+    let entity = spawn_instance(&mut app, &mesh, rotated);
+    app.update();
+    let before = app.world.get::<Aabb>(entity).unwrap().clone();
+
+    // The volume is stored in mesh space, so moving the instance leaves it untouched.
+    app.world
+        .get_mut::<GlobalTransform>(entity)
+        .unwrap()
+        .translation = Vec3::new(5.0, -3.0, 1.0);
+    app.update();
+    assert!(app
+        .world
+        .get::<Aabb>(entity)
+        .unwrap()
+        .approx_eq(&before, 1e-6));
+
+    // Rotating it changes the mesh-space extents of the axis aligned box.
+    let turned = GlobalTransform::from_rotation(Quat::from_rotation_y(1.2));
+    *app.world.get_mut::<GlobalTransform>(entity).unwrap() = turned;
+    app.update();
+    assert_bounds(&app, entity, &vertices, &turned);
+    assert!(!app
+        .world
+        .get::<Aabb>(entity)
+        .unwrap()
+        .approx_eq(&before, 1e-3));
+}
@@ -0,0 +1,103 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_mod_bounding::{sphere::BSphere, BoundingError, BoundingVolume, FitMethod};
+use common::{box_corners, points_mesh};
+
+#[test]
+fn duplicated_vertices_dont_change_the_sphere() {
+    let transform = GlobalTransform::identity();
+    let corners = box_corners(Vec3::ONE, Quat::IDENTITY);
+    let sphere = BSphere::from_vertices(&corners, &transform);
+    // Each corner repeated once per face that meets it, like a cube mesh with split normals, in
+    // an order that differs from the plain corners.
+    let duplicated: Vec<Vec3> = corners
+        .iter()
+        .rev()
+        .chain(corners.iter())
+        .chain(corners.iter().rev())
+        .copied()
+        .collect();
+    let duplicated_sphere = BSphere::from_vertices(&duplicated, &transform);
+    assert_eq!(
+        duplicated_sphere.mesh_space_radius(),
+        sphere.mesh_space_radius()
+    );
+    assert_eq!(
+        duplicated_sphere.mesh_space_origin(),
+        sphere.mesh_space_origin()
+    );
+    // The cube mesh of bevy has 24 vertices, with each corner duplicated three times.
+    let cube_sphere = BSphere::new(&Mesh::from(shape::Cube { size: 2.0 }), &transform).unwrap();
+    assert_eq!(cube_sphere.mesh_space_radius(), sphere.mesh_space_radius());
+}
+
+/// Asserts that every vertex lies within the radius of the sphere, up to float error.
+fn assert_encloses(sphere: &BSphere, vertices: &[Vec3]) {
+    for vertex in vertices {
+        let distance = vertex.distance(*sphere.mesh_space_origin());
+        assert!(
+            distance <= sphere.mesh_space_radius() + 1e-5,
+            "{:?} is {} from the origin, outside of the radius {}",
+            vertex,
+            distance,
+            sphere.mesh_space_radius()
+        );
+    }
+}
+
+#[test]
+fn verification_pass_corrects_a_bad_fit() {
+    let vertices = box_corners(Vec3::new(2.0, 1.0, 0.5), Quat::from_rotation_y(0.4));
+    // Off center, and far too small to enclose the box.
+    let bad_fit = BSphere::from_origin_radius(Vec3::new(0.5, -0.25, 0.1), 0.5);
+    let corrected = bad_fit.grown_to_enclose(&vertices);
+    assert_eq!(corrected.mesh_space_origin(), bad_fit.mesh_space_origin());
+    assert_encloses(&corrected, &vertices);
+    // A sphere that already encloses every vertex is left as is.
+    let loose = BSphere::from_origin_radius(Vec3::ZERO, 10.0);
+    assert_eq!(loose.grown_to_enclose(&vertices).mesh_space_radius(), &10.0);
+}
+
+#[test]
+fn fit_encloses_every_vertex_far_from_the_origin() {
+    // Far from the origin, moving the center of the sphere loses the most precision.
+    let offset = Vec3::new(1e4, -3e4, 2e4);
+    let vertices: Vec<Vec3> = box_corners(Vec3::new(2.0, 1.0, 0.5), Quat::from_rotation_y(0.4))
+        .into_iter()
+        .chain(vec![Vec3::new(0.3, 1.2, -0.7), Vec3::new(-1.9, 0.1, 0.6)])
+        .map(|vertex| vertex + offset)
+        .collect();
+    let sphere = BSphere::from_vertices(&vertices, &GlobalTransform::identity());
+    assert_encloses(&sphere, &vertices);
+}
+
+#[test]
+fn robust_fit_rejects_an_invalid_percentile() {
+    let mesh = points_mesh(&box_corners(Vec3::ONE, Quat::IDENTITY));
+    let transform = GlobalTransform::identity();
+    for &percentile in [-1.0, 100.0, f32::NAN].iter() {
+        assert!(matches!(
+            BSphere::new_robust(&mesh, &transform, percentile),
+            Err(BoundingError::InvalidParameter(_))
+        ));
+    }
+    assert!(BSphere::new_robust(&mesh, &transform, 10.0).is_ok());
+}
+
+#[test]
+fn support_follows_the_fit_method() {
+    let vertices = box_corners(Vec3::new(2.0, 1.0, 0.5), Quat::from_rotation_y(0.3));
+    let transform = GlobalTransform::identity();
+    for &method in [FitMethod::Precise, FitMethod::Fast].iter() {
+        let (sphere, _) = BSphere::from_vertices_with_method_and_support(&vertices, Some(method));
+        let fit = BSphere::from_vertices_with_method(&vertices, &transform, method);
+        assert!(sphere.approx_eq(&fit, 0.0), "{:?}", method);
+    }
+    // The centroid seeded sphere starts as a point at the centroid, which is the box center.
+    let (_, support) =
+        BSphere::from_vertices_with_method_and_support(&vertices, Some(FitMethod::Fast));
+    assert!(support.poles.0.length() < 1e-5);
+    assert_eq!(support.poles.0, support.poles.1);
+    assert!(support.last_grown_to.is_some());
+}
@@ -0,0 +1,31 @@
+use bevy::{prelude::*, render::pipeline::PrimitiveTopology};
+use bevy_mod_bounding::{aabb::Aabb, obb::Obb, sphere::BSphere, BoundingVolume};
+
+/// The outline of the cube as an indexed line list, like the debug mesh of a box.
+fn cube_outline() -> Mesh {
+    let outline = Mesh::from(&Aabb::from_extents(Vec3::splat(-1.0), Vec3::splat(1.0)));
+    assert_eq!(outline.primitive_topology(), PrimitiveTopology::LineList);
+    assert!(outline.indices().is_some());
+    outline
+}
+
+#[test]
+fn line_list_is_bounded_like_the_solid_mesh() {
+    let transform = GlobalTransform::from_rotation(Quat::from_rotation_y(0.3));
+    let solid = Mesh::from(shape::Cube { size: 2.0 });
+    let outline = cube_outline();
+
+    let solid_aabb = Aabb::new(&solid, &transform).unwrap();
+    let outline_aabb = Aabb::new(&outline, &transform).unwrap();
+    assert_eq!(outline_aabb.minimums(), solid_aabb.minimums());
+    assert_eq!(outline_aabb.maximums(), solid_aabb.maximums());
+
+    let solid_obb = Obb::new(&solid, &transform).unwrap();
+    assert!(Obb::new(&outline, &transform)
+        .unwrap()
+        .approx_eq(&solid_obb, 0.0));
+    let solid_sphere = BSphere::new(&solid, &transform).unwrap();
+    assert!(BSphere::new(&outline, &transform)
+        .unwrap()
+        .approx_eq(&solid_sphere, 0.0));
+}
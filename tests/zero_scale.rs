@@ -0,0 +1,47 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_mod_bounding::{
+    aabb::Aabb, obb::Obb, sphere::BSphere, BoundingVolume, ZeroScalePolicy, MIN_SCALE,
+};
+use common::box_corners;
+
+fn zero_scale() -> GlobalTransform {
+    GlobalTransform {
+        translation: Vec3::new(1.0, 2.0, 3.0),
+        scale: Vec3::ZERO,
+        ..Default::default()
+    }
+}
+
+fn is_finite(vector: Vec3) -> bool {
+    vector.x.is_finite() && vector.y.is_finite() && vector.z.is_finite()
+}
+
+#[test]
+fn skip_keeps_the_last_bounding_volume() {
+    assert_eq!(ZeroScalePolicy::Skip.apply(&zero_scale()), None);
+    let valid = GlobalTransform::from_scale(Vec3::splat(2.0));
+    assert_eq!(ZeroScalePolicy::Skip.apply(&valid), Some(valid));
+}
+
+#[test]
+fn clamp_fits_finite_bounding_volumes() {
+    let transform = ZeroScalePolicy::Clamp(0.01)
+        .apply(&zero_scale())
+        .expect("Clamping never skips the fit");
+    assert!(transform.scale.abs().min_element() >= MIN_SCALE);
+
+    let vertices = box_corners(Vec3::ONE, Quat::IDENTITY);
+    let aabb = Aabb::from_vertices(&vertices, &transform);
+    assert!(is_finite(aabb.minimums()) && is_finite(aabb.maximums()));
+    let sphere = BSphere::from_aabb(&aabb, &transform);
+    assert!(is_finite(*sphere.mesh_space_origin()) && sphere.mesh_space_radius().is_finite());
+    let sphere = BSphere::from_vertices(&vertices, &transform);
+    assert!(sphere.radius(&transform).is_finite());
+    let obb = Obb::from_vertices(&vertices, &transform);
+    assert!(obb
+        .vertices(transform)
+        .iter()
+        .all(|&vertex| is_finite(vertex)));
+}